@@ -0,0 +1,25 @@
+//! Builds a frame without any window or renderer, and inspects the resulting draw data.
+//!
+//! This is how UI layout can be checked in CI, where there is no GPU.
+
+use imgui::*;
+
+fn main() {
+    let frame = headless(|ui| {
+        ui.window("Headless")
+            .position([10.0, 20.0], Condition::Always)
+            .build(|| {
+                ui.text("Hello from a headless frame");
+                ui.button("Button");
+            });
+    });
+
+    println!("{} vertices, {} indices", frame.vtx_count, frame.idx_count);
+    for cmd in &frame.cmds {
+        // The font atlas is bound to HEADLESS_FONT_TEXTURE
+        println!(
+            "  {} indices, texture {:?}, clip rect {:?}",
+            cmd.count, cmd.texture_id, cmd.clip_rect
+        );
+    }
+}
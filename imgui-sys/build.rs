@@ -49,6 +49,17 @@ fn main() -> std::io::Result<()> {
         (true, true) => "third-party/imgui-docking-freetype",
     });
 
+    // Expose the version of the bundled Dear ImGui as `sys::DEAR_IMGUI_VERSION`
+    let imgui_h = cimgui_dir.join("imgui").join("imgui.h");
+    let version = std::fs::read_to_string(&imgui_h)?
+        .lines()
+        .find_map(|line| {
+            let rest = line.strip_prefix("#define IMGUI_VERSION ")?;
+            Some(rest.trim().trim_matches('"').to_owned())
+        })
+        .expect("IMGUI_VERSION not found in imgui.h");
+    println!("cargo:rustc-env=DEAR_IMGUI_VERSION={}", version);
+
     // For projects like implot-rs we expose the path to our cimgui
     // files, via `DEP_IMGUI_THIRD_PARTY` env-var, so they can build
     // against the same thing
@@ -72,6 +72,9 @@ cfg_if::cfg_if! {
     }
 }
 
+/// Version of the bundled Dear ImGui (`IMGUI_VERSION`), e.g. `"1.89.2"`
+pub const DEAR_IMGUI_VERSION: &str = env!("DEAR_IMGUI_VERSION");
+
 impl ImVec2 {
     #[inline]
     pub const fn new(x: f32, y: f32) -> ImVec2 {
@@ -96,13 +96,19 @@ pub(crate) unsafe extern "C" fn set_clipboard_text(user_data: *mut c_void, text:
 impl Ui {
     /// Returns the current clipboard contents as text, or None if the clipboard is empty or cannot
     /// be accessed
+    ///
+    /// This goes through the backend set with
+    /// [`Context::set_clipboard_backend`](crate::Context::set_clipboard_backend), or the
+    /// clipboard functions installed by a platform backend. Without either, Dear ImGui falls
+    /// back to a clipboard private to the current process.
+    #[doc(alias = "GetClipboardText")]
     pub fn clipboard_text(&self) -> Option<String> {
         let io = self.io();
         io.get_clipboard_text_fn.and_then(|get_clipboard_text_fn| {
             // Bypass FFI if we end up calling our own function anyway
             if get_clipboard_text_fn == get_clipboard_text {
                 let ctx = unsafe { &mut *(io.clipboard_user_data as *mut ClipboardContext) };
-                ctx.backend.get()
+                ctx.backend.get().filter(|text| !text.is_empty())
             } else {
                 unsafe {
                     let text_ptr = get_clipboard_text_fn(io.clipboard_user_data);
@@ -124,7 +130,8 @@ impl Ui {
 
     /// Sets the clipboard contents.
     ///
-    /// Does nothing if the clipboard cannot be accessed.
+    /// Does nothing if the clipboard cannot be accessed, see [`clipboard_text`](Self::clipboard_text).
+    #[doc(alias = "SetClipboardText")]
     pub fn set_clipboard_text(&self, text: impl AsRef<str>) {
         let io = self.io();
         if let Some(set_clipboard_text_fn) = io.set_clipboard_text_fn {
@@ -140,3 +147,41 @@ impl Ui {
         }
     }
 }
+
+#[cfg(test)]
+pub(crate) struct MockClipboard(pub(crate) Option<String>);
+
+#[cfg(test)]
+impl ClipboardBackend for MockClipboard {
+    fn get(&mut self) -> Option<String> {
+        self.0.clone()
+    }
+    fn set(&mut self, value: &str) {
+        self.0 = Some(value.to_owned());
+    }
+}
+
+#[test]
+fn test_clipboard_text_round_trip() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.set_clipboard_backend(MockClipboard(None));
+    let ui = ctx.new_frame();
+    assert_eq!(ui.clipboard_text(), None);
+    ui.set_clipboard_text("copied");
+    assert_eq!(ui.clipboard_text().as_deref(), Some("copied"));
+    // Dear ImGui itself (e.g. the text inputs) sees the same clipboard
+    let raw = unsafe { CStr::from_ptr(crate::sys::igGetClipboardText()) };
+    assert_eq!(raw.to_str(), Ok("copied"));
+    ui.set_clipboard_text("");
+    assert_eq!(ui.clipboard_text(), None);
+}
+
+#[test]
+fn test_clipboard_text_default_backend() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    ui.set_clipboard_text("in process");
+    assert_eq!(ui.clipboard_text().as_deref(), Some("in process"));
+    ui.set_clipboard_text("");
+    assert_eq!(ui.clipboard_text(), None);
+}
@@ -4,14 +4,18 @@ use crate::Ui;
 /// # Columns
 impl Ui {
     #[doc(alias = "Columns")]
+    #[track_caller]
     pub fn columns(&self, count: i32, id: impl AsRef<str>, border: bool) {
+        self.assert_in_frame("Ui::columns");
         unsafe { sys::igColumns(count, self.scratch_txt(id), border) }
     }
     /// Switches to the next column.
     ///
     /// If the current row is finished, switches to first column of the next row
     #[doc(alias = "NextColumn")]
+    #[track_caller]
     pub fn next_column(&self) {
+        self.assert_in_frame("Ui::next_column");
         unsafe { sys::igNextColumn() }
     }
     /// Returns the index of the current column
@@ -31,12 +35,16 @@ impl Ui {
     }
     #[doc(alias = "SetColumnWidth")]
     /// Sets the width of the current column (in pixels)
+    #[track_caller]
     pub fn set_current_column_width(&self, width: f32) {
+        self.assert_in_frame("Ui::set_current_column_width");
         unsafe { sys::igSetColumnWidth(-1, width) };
     }
     #[doc(alias = "SetColumnWidth")]
     /// Sets the width of the given column (in pixels)
+    #[track_caller]
     pub fn set_column_width(&self, column_index: i32, width: f32) {
+        self.assert_in_frame("Ui::set_column_width");
         unsafe { sys::igSetColumnWidth(column_index, width) };
     }
     /// Returns the offset of the current column (in pixels from the left side of the content
@@ -52,12 +60,16 @@ impl Ui {
     }
     /// Sets the offset of the current column (in pixels from the left side of the content region)
     #[doc(alias = "SetColumnOffset")]
+    #[track_caller]
     pub fn set_current_column_offset(&self, offset_x: f32) {
+        self.assert_in_frame("Ui::set_current_column_offset");
         unsafe { sys::igSetColumnOffset(-1, offset_x) };
     }
     /// Sets the offset of the given column (in pixels from the left side of the content region)
     #[doc(alias = "SetColumnOffset")]
+    #[track_caller]
     pub fn set_column_offset(&self, column_index: i32, offset_x: f32) {
+        self.assert_in_frame("Ui::set_column_offset");
         unsafe { sys::igSetColumnOffset(column_index, offset_x) };
     }
     /// Returns the current amount of columns
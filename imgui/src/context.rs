@@ -1,7 +1,9 @@
 use parking_lot::ReentrantMutex;
-use std::cell::UnsafeCell;
+use std::any::Any;
+use std::cell::{Cell, UnsafeCell};
 use std::ffi::{CStr, CString};
 use std::ops::Drop;
+use std::os::raw::c_void;
 use std::path::PathBuf;
 use std::ptr;
 
@@ -12,6 +14,38 @@ use crate::style::Style;
 use crate::{sys, DrawData};
 use crate::{MouseCursor, Ui};
 
+const STATE_MAGIC: &[u8; 4] = b"IMRS";
+const STATE_VERSION: u32 = 1;
+const STATE_HEADER_LEN: usize = 12;
+
+/// Error returned by [`Context::load_state`]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum LoadStateError {
+    /// The data wasn't written by [`Context::save_state`]
+    NotAStateBlob,
+    /// The blob was written by an incompatible version of imgui-rs
+    UnsupportedVersion(u32),
+    /// The blob is truncated or damaged
+    Corrupted,
+}
+
+impl std::fmt::Display for LoadStateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadStateError::NotAStateBlob => f.pad("Data is not an imgui-rs state blob"),
+            LoadStateError::UnsupportedVersion(version) => write!(
+                f,
+                "Unsupported state blob version {} -- expected {}",
+                version, STATE_VERSION
+            ),
+            LoadStateError::Corrupted => f.pad("State blob is corrupted"),
+        }
+    }
+}
+
+impl std::error::Error for LoadStateError {}
+
 /// An imgui-rs context.
 ///
 /// A context needs to be created to access most library functions. Due to current Dear ImGui
@@ -120,6 +154,30 @@ impl Context {
     /// Sets the path to the ini file (default is "imgui.ini")
     ///
     /// Pass None to disable automatic .Ini saving.
+    ///
+    /// With no ini file, Dear ImGui never touches the filesystem: nothing is loaded on the first
+    /// frame and nothing is written back. The application is then responsible for persistence,
+    /// using [`load_ini_settings`](Self::load_ini_settings) before the first frame, and
+    /// [`save_ini_settings`](Self::save_ini_settings) whenever
+    /// [`Io::want_save_ini_settings`] is raised.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # let mut ctx = imgui::Context::create();
+    /// # let stored_settings = String::new();
+    /// ctx.set_ini_filename(None);
+    /// ctx.io_mut().set_ini_saving_rate(1.0);
+    /// ctx.load_ini_settings(&stored_settings);
+    ///
+    /// // ... after each frame:
+    /// if ctx.io().want_save_ini_settings() {
+    ///     let mut buf = String::new();
+    ///     ctx.save_ini_settings(&mut buf);
+    ///     // write `buf` to wherever the settings are kept
+    ///     ctx.io_mut().clear_want_save_ini_settings();
+    /// }
+    /// ```
     pub fn set_ini_filename<T: Into<Option<PathBuf>>>(&mut self, ini_filename: T) {
         let ini_filename: Option<PathBuf> = ini_filename.into();
         let ini_filename = ini_filename.and_then(|v| CString::new(v.to_str()?).ok());
@@ -198,7 +256,14 @@ impl Context {
     /// Loads settings from a string slice containing settings in .Ini file format
     #[doc(alias = "LoadIniSettingsFromMemory")]
     pub fn load_ini_settings(&mut self, data: &str) {
-        unsafe { sys::igLoadIniSettingsFromMemory(data.as_ptr() as *const _, data.len()) }
+        // Dear ImGui treats a size of 0 as "nul-terminated", so an empty string must still point
+        // at a valid nul byte
+        let ptr = if data.is_empty() {
+            b"\0".as_ptr()
+        } else {
+            data.as_ptr()
+        };
+        unsafe { sys::igLoadIniSettingsFromMemory(ptr as *const _, data.len()) }
     }
     /// Saves settings to a mutable string buffer in .Ini file format
     #[doc(alias = "SaveInitSettingsToMemory")]
@@ -206,6 +271,96 @@ impl Context {
         let data = unsafe { CStr::from_ptr(sys::igSaveIniSettingsToMemory(ptr::null_mut())) };
         buf.push_str(&data.to_string_lossy());
     }
+    /// Saves all layout-related state into a binary blob, for restoring a session with
+    /// [`load_state`](Self::load_state).
+    ///
+    /// The blob contains the .ini settings (window positions and sizes, table settings, and the
+    /// dock layout with the `docking` feature) and the
+    /// [color editor options](crate::Ui::set_color_edit_options). The color editor options can
+    /// only be read with the `docking` feature, and are left out otherwise.
+    ///
+    /// The blob starts with a version header, so blobs written by incompatible versions of
+    /// imgui-rs are rejected instead of being misread.
+    pub fn save_state(&self) -> Vec<u8> {
+        let ini = unsafe { CStr::from_ptr(sys::igSaveIniSettingsToMemory(ptr::null_mut())) };
+        #[cfg(feature = "docking")]
+        let color_edit_options = unsafe { (*self.raw).ColorEditOptions } as u32;
+        #[cfg(not(feature = "docking"))]
+        let color_edit_options = 0u32;
+
+        let mut blob = Vec::with_capacity(STATE_HEADER_LEN + ini.to_bytes().len());
+        blob.extend_from_slice(STATE_MAGIC);
+        blob.extend_from_slice(&STATE_VERSION.to_le_bytes());
+        blob.extend_from_slice(&color_edit_options.to_le_bytes());
+        blob.extend_from_slice(ini.to_bytes());
+        blob
+    }
+    /// Restores state saved with [`save_state`](Self::save_state).
+    ///
+    /// Like [`load_ini_settings`](Self::load_ini_settings), this should be called before the
+    /// first frame, as windows that already exist keep their current position and size. Nothing
+    /// is changed if the blob is rejected.
+    pub fn load_state(&mut self, data: &[u8]) -> Result<(), LoadStateError> {
+        let read_u32 = |offset: usize| {
+            data.get(offset..offset + 4)
+                .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        };
+        if !data.starts_with(STATE_MAGIC) {
+            return Err(LoadStateError::NotAStateBlob);
+        }
+        let version = read_u32(STATE_MAGIC.len()).ok_or(LoadStateError::Corrupted)?;
+        if version != STATE_VERSION {
+            return Err(LoadStateError::UnsupportedVersion(version));
+        }
+        let color_edit_options = read_u32(8).ok_or(LoadStateError::Corrupted)?;
+        let ini = std::str::from_utf8(&data[STATE_HEADER_LEN..])
+            .map_err(|_| LoadStateError::Corrupted)?;
+        if color_edit_options != 0 {
+            if crate::ColorEditFlags::from_bits(color_edit_options).is_none() {
+                return Err(LoadStateError::Corrupted);
+            }
+            // Dear ImGui asserts that at most one option of each kind is set
+            let masks = [
+                sys::ImGuiColorEditFlags_DisplayMask_,
+                sys::ImGuiColorEditFlags_DataTypeMask_,
+                sys::ImGuiColorEditFlags_PickerMask_,
+                sys::ImGuiColorEditFlags_InputMask_,
+            ];
+            if masks
+                .iter()
+                .any(|&mask| (color_edit_options & mask).count_ones() > 1)
+            {
+                return Err(LoadStateError::Corrupted);
+            }
+        }
+
+        self.load_ini_settings(ini);
+        if color_edit_options != 0 {
+            unsafe { sys::igSetColorEditOptions(color_edit_options as i32) };
+        }
+        Ok(())
+    }
+    /// Stores a value for the application, replacing the previous one.
+    ///
+    /// The value can be retrieved as a `&T` with [`user_data`](Self::user_data) and
+    /// [`Ui::user_data`], e.g. from callbacks that have access to the `Ui`. It lives until it is
+    /// replaced or the context is dropped; use interior mutability (e.g. `RefCell`) to change it
+    /// in place.
+    ///
+    /// [`Io::user_data`] is set to point to the value, for callbacks going through C code. That
+    /// pointer is untyped: it's up to the reader to cast it back to the right `T`, and it's no
+    /// longer valid once the value has been replaced.
+    pub fn set_user_data<T: Any>(&mut self, data: T) {
+        let mut data: Box<dyn Any> = Box::new(data);
+        self.io_mut()
+            .set_user_data(&mut *data as *mut dyn Any as *mut c_void);
+        self.ui.user_data = Some(data);
+    }
+    /// Returns the value set with [`set_user_data`](Self::set_user_data), or `None` if there is
+    /// none or it isn't a `T`.
+    pub fn user_data<T: Any>(&self) -> Option<&T> {
+        self.ui.user_data()
+    }
     /// Sets the clipboard backend used for clipboard operations
     pub fn set_clipboard_backend<T: ClipboardBackend>(&mut self, backend: T) {
         let clipboard_ctx: Box<UnsafeCell<_>> = Box::new(ClipboardContext::new(backend).into());
@@ -241,6 +396,11 @@ impl Context {
             clipboard_ctx: Box::new(ClipboardContext::dummy().into()),
             ui: Ui {
                 buffer: UnsafeCell::new(crate::string::UiBuffer::new(1024)),
+                frame_arena: UnsafeCell::new(crate::string::FrameArena::default()),
+                user_data: None,
+                log_active: Cell::new(false),
+                #[cfg(debug_assertions)]
+                in_frame: false,
             },
         }
     }
@@ -306,6 +466,7 @@ impl SuspendedContext {
     /// If there is already an active context, nothing happens and `Err` is returned, containing
     /// the original suspended context.
     #[doc(alias = "SetCurrentContext")]
+    #[allow(clippy::result_large_err)] // Both variants are the same context, just moved back
     pub fn activate(self) -> Result<Context, SuspendedContext> {
         let _guard = CTX_MUTEX.lock();
         if no_current_context() {
@@ -330,6 +491,11 @@ impl SuspendedContext {
             clipboard_ctx: Box::new(ClipboardContext::dummy().into()),
             ui: Ui {
                 buffer: UnsafeCell::new(crate::string::UiBuffer::new(1024)),
+                frame_arena: UnsafeCell::new(crate::string::FrameArena::default()),
+                user_data: None,
+                log_active: Cell::new(false),
+                #[cfg(debug_assertions)]
+                in_frame: false,
             },
         };
         if ctx.is_current_context() {
@@ -436,6 +602,98 @@ Collapsed=0";
     assert_eq!(data.trim(), buf.trim());
 }
 
+#[test]
+fn test_state_round_trip() {
+    let blob = {
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        for _ in 0..2 {
+            let ui = ctx.new_frame();
+            ui.window("Saved")
+                .position([120.0, 80.0], crate::Condition::Always)
+                .build(|| ());
+            #[cfg(feature = "docking")]
+            ui.set_color_edit_options(crate::ColorEditFlags::DISPLAY_HEX);
+            let _ = ctx.render();
+        }
+        ctx.save_state()
+    };
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    assert_eq!(ctx.load_state(&blob), Ok(()));
+    let ui = ctx.new_frame();
+    ui.window("Saved").build(|| {
+        assert_eq!(ui.window_pos(), [120.0, 80.0]);
+    });
+    #[cfg(feature = "docking")]
+    assert!(ui
+        .color_edit_options()
+        .contains(crate::ColorEditFlags::DISPLAY_HEX));
+}
+
+#[test]
+fn test_load_state_rejects_invalid_blobs() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let blob = ctx.save_state();
+    assert_eq!(
+        ctx.load_state(b"[Window][Debug##Default]"),
+        Err(LoadStateError::NotAStateBlob)
+    );
+    let mut newer = blob.clone();
+    newer[4..8].copy_from_slice(&2u32.to_le_bytes());
+    assert_eq!(
+        ctx.load_state(&newer),
+        Err(LoadStateError::UnsupportedVersion(2))
+    );
+    assert_eq!(ctx.load_state(&blob[..6]), Err(LoadStateError::Corrupted));
+    let mut bad_options = blob;
+    bad_options[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(ctx.load_state(&bad_options), Err(LoadStateError::Corrupted));
+}
+
+#[test]
+fn test_user_data_reaches_callbacks() {
+    use crate::{InputTextCallback, InputTextCallbackHandler, TextCallbackData};
+    use std::cell::Cell;
+
+    struct AppState {
+        callback_calls: Cell<u32>,
+    }
+    struct Handler<'ui>(&'ui Ui);
+    impl InputTextCallbackHandler for Handler<'_> {
+        fn on_always(&mut self, _: TextCallbackData) {
+            let state = self.0.user_data::<AppState>().unwrap();
+            state.callback_calls.set(state.callback_calls.get() + 1);
+        }
+    }
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    assert!(ctx.user_data::<AppState>().is_none());
+    assert!(ctx.io().user_data().is_null());
+    ctx.set_user_data(AppState {
+        callback_calls: Cell::new(0),
+    });
+    assert!(ctx.user_data::<u32>().is_none());
+    let state: *const AppState = ctx.user_data::<AppState>().unwrap();
+    assert_eq!(ctx.io().user_data() as *const AppState, state);
+
+    let mut text = String::new();
+    for _ in 0..3 {
+        let ui = ctx.new_frame();
+        ui.window("Input").build(|| {
+            ui.set_keyboard_focus_here();
+            ui.input_text("Text", &mut text)
+                .callback(InputTextCallback::ALWAYS, Handler(ui))
+                .build();
+        });
+        let _ = ctx.render();
+    }
+    let calls = ctx.user_data::<AppState>().unwrap().callback_calls.get();
+    assert!(calls > 0);
+
+    ctx.set_user_data(5u32);
+    assert!(ctx.user_data::<AppState>().is_none());
+    assert_eq!(ctx.user_data::<u32>(), Some(&5));
+}
+
 #[test]
 fn test_default_ini_filename() {
     let _guard = crate::test::TEST_MUTEX.lock();
@@ -495,6 +753,18 @@ impl Context {
             &mut *(sys::igGetStyle() as *mut Style)
         }
     }
+    /// Returns a copy of the user interface style.
+    ///
+    /// Unlike [`style`](Self::style), the returned value isn't tied to the context, so several
+    /// styles (e.g. themes) can be kept around and applied later with
+    /// [`set_style`](Self::set_style).
+    pub fn clone_style(&self) -> Style {
+        *self.style()
+    }
+    /// Replaces the user interface style with a copy of `style`, including all colors and sizes.
+    pub fn set_style(&mut self, style: &Style) {
+        *self.style_mut() = *style;
+    }
     /// Returns a mutable reference to the font atlas.
     pub fn fonts(&mut self) -> &mut FontAtlas {
         // we take this with an `&mut Self` here, which means
@@ -502,6 +772,26 @@ impl Context {
         // otherwise
         unsafe { &mut *(self.io_mut().fonts as *mut FontAtlas) }
     }
+    /// Modifies the font atlas between frames, e.g. to add fonts or change their size, and
+    /// rebuilds it if needed.
+    ///
+    /// Returns true if the atlas was rebuilt, in which case the renderer has to re-upload the
+    /// font texture before rendering the next frame: get the new pixels with
+    /// [`FontAtlas::build_rgba32_texture`] (or [`FontAtlas::build_alpha8_texture`]), upload them,
+    /// free the old texture and set [`FontAtlas::tex_id`] to the new one. Fonts previously
+    /// returned by [`FontAtlas::add_font`] keep their [`FontId`]s unless `f` clears them.
+    ///
+    /// This borrows the context mutably, so it can't be called while a frame is in progress.
+    /// `f` shouldn't build the atlas itself, as the rebuild then isn't detected.
+    pub fn rebuild_font_atlas(&mut self, f: impl FnOnce(&mut FontAtlas)) -> bool {
+        let fonts = self.fonts();
+        f(fonts);
+        if fonts.is_built() {
+            return false;
+        }
+        unsafe { sys::ImFontAtlas_Build(fonts as *mut FontAtlas as *mut sys::ImFontAtlas) };
+        true
+    }
 
     /// Attempts to clone the interior shared font atlas **if it exists**.
     pub fn clone_shared_font_atlas(&mut self) -> Option<SharedFontAtlas> {
@@ -516,8 +806,12 @@ impl Context {
     }
 
     /// Starts a new frame and returns an `Ui` instance for constructing a user interface.
+    ///
+    /// With the `tracing` feature, this is traced as an `imgui::new_frame` span.
     #[doc(alias = "NewFame")]
     pub fn new_frame(&mut self) -> &mut Ui {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("imgui::new_frame").entered();
         // Clear default font if it no longer exists. This could be an error in the future
         let default_font = self.io().font_default;
         if !default_font.is_null() && self.fonts().get_font(FontId(default_font)).is_none() {
@@ -527,6 +821,12 @@ impl Context {
         unsafe {
             sys::igNewFrame();
         }
+        self.ui.frame_arena.get_mut().reset();
+        self.ui.log_active.set(false);
+        #[cfg(debug_assertions)]
+        {
+            self.ui.in_frame = true;
+        }
 
         &mut self.ui
     }
@@ -535,9 +835,17 @@ impl Context {
     ///
     /// This should only be called after calling [`new_frame`].
     ///
+    /// With the `tracing` feature, this is traced as an `imgui::render` span.
+    ///
     /// [`new_frame`]: Self::new_frame
     #[doc(alias = "Render", alias = "GetDrawData")]
     pub fn render(&mut self) -> &DrawData {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("imgui::render").entered();
+        #[cfg(debug_assertions)]
+        {
+            self.ui.in_frame = false;
+        }
         unsafe {
             sys::igRender();
             &*(sys::igGetDrawData() as *mut DrawData)
@@ -568,3 +876,139 @@ impl Context {
         }
     }
 }
+
+#[cfg(feature = "docking")]
+bitflags::bitflags! {
+    /// Categories of events recorded in the [debug log](Context::debug_log)
+    #[repr(transparent)]
+    pub struct DebugLogFlags: u32 {
+        /// Changes of the active (e.g. clicked or edited) item
+        const EVENT_ACTIVE_ID = sys::ImGuiDebugLogFlags_EventActiveId;
+        /// Window focus changes
+        const EVENT_FOCUS = sys::ImGuiDebugLogFlags_EventFocus;
+        /// Popups opening and closing
+        const EVENT_POPUP = sys::ImGuiDebugLogFlags_EventPopup;
+        /// Keyboard/gamepad navigation requests and results
+        const EVENT_NAV = sys::ImGuiDebugLogFlags_EventNav;
+        /// List clipper steps
+        const EVENT_CLIPPER = sys::ImGuiDebugLogFlags_EventClipper;
+        /// Processed input events
+        const EVENT_IO = sys::ImGuiDebugLogFlags_EventIO;
+        /// Docking changes
+        const EVENT_DOCKING = sys::ImGuiDebugLogFlags_EventDocking;
+        /// Viewport changes
+        const EVENT_VIEWPORT = sys::ImGuiDebugLogFlags_EventViewport;
+        /// Also print every log line to stdout
+        const OUTPUT_TO_TTY = sys::ImGuiDebugLogFlags_OutputToTTY;
+    }
+}
+
+/// # Debug log
+///
+/// **Unstable**, see [Unstable APIs](crate#unstable-apis).
+#[cfg(feature = "docking")]
+impl Context {
+    /// Returns the text recorded in the debug log so far.
+    ///
+    /// Only events of the categories enabled with
+    /// [`set_debug_log_flags`](Self::set_debug_log_flags) are recorded. The log keeps growing
+    /// until it is [cleared](Self::clear_debug_log).
+    #[doc(alias = "DebugLog")]
+    pub fn debug_log(&self) -> &str {
+        unsafe {
+            let buf = &mut (*self.raw).DebugLogBuf;
+            let bytes = CStr::from_ptr(sys::ImGuiTextBuffer_c_str(buf)).to_bytes();
+            std::str::from_utf8(bytes).unwrap_or_default()
+        }
+    }
+    /// Clears the debug log.
+    pub fn clear_debug_log(&mut self) {
+        unsafe { sys::ImGuiTextBuffer_clear(&mut (*self.raw).DebugLogBuf) }
+    }
+    /// Returns the categories of events recorded in the debug log.
+    pub fn debug_log_flags(&self) -> DebugLogFlags {
+        DebugLogFlags::from_bits_truncate(unsafe { (*self.raw).DebugLogFlags } as u32)
+    }
+    /// Sets the categories of events recorded in the debug log.
+    pub fn set_debug_log_flags(&mut self, flags: DebugLogFlags) {
+        unsafe { (*self.raw).DebugLogFlags = flags.bits() as sys::ImGuiDebugLogFlags }
+    }
+}
+
+/// # Popups
+#[cfg(feature = "docking")]
+impl Context {
+    /// Closes all open popups, including modal popups, between frames.
+    ///
+    /// Use this to dismiss popups after a change of application state that invalidates them,
+    /// e.g. when loading another document. To only close the popups that don't belong to the
+    /// current window during a frame, use [`Ui::close_popups_over_window`].
+    ///
+    /// **Unstable**, see [Unstable APIs](crate#unstable-apis).
+    #[doc(alias = "ClosePopupToLevel")]
+    pub fn close_all_popups(&mut self) {
+        unsafe {
+            if (*self.raw).OpenPopupStack.Size > 0 {
+                sys::igClosePopupToLevel(0, true);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "docking")]
+#[test]
+fn test_debug_log_records_enabled_events() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    assert!(ctx.debug_log().is_empty());
+    ctx.io_mut().add_mouse_pos_event([10.0, 20.0]);
+    let _ = ctx.new_frame();
+    let _ = ctx.render();
+    // Nothing is recorded while the category is disabled
+    assert!(!ctx.debug_log().contains("MousePos"));
+
+    ctx.set_debug_log_flags(DebugLogFlags::EVENT_IO);
+    assert_eq!(ctx.debug_log_flags(), DebugLogFlags::EVENT_IO);
+    ctx.io_mut().add_mouse_pos_event([30.0, 40.0]);
+    let _ = ctx.new_frame();
+    let _ = ctx.render();
+    assert!(ctx.debug_log().contains("MousePos (30.0, 40.0)"));
+
+    ctx.clear_debug_log();
+    assert!(ctx.debug_log().is_empty());
+}
+
+#[test]
+fn test_rebuild_font_atlas() {
+    use crate::fonts::atlas::{FontConfig, FontSource};
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let default_font = ctx.fonts().fonts()[0];
+    assert_eq!(ctx.fonts().fonts().len(), 1);
+
+    let mut large_font = None;
+    let dirty = ctx.rebuild_font_atlas(|atlas| {
+        large_font = Some(atlas.add_font(&[FontSource::DefaultFontData {
+            config: Some(FontConfig {
+                size_pixels: 26.0,
+                ..FontConfig::default()
+            }),
+        }]));
+    });
+    assert!(dirty);
+    assert!(ctx.fonts().is_built());
+    assert_eq!(ctx.fonts().fonts(), vec![default_font, large_font.unwrap()]);
+    assert_eq!(
+        ctx.fonts().get_font(large_font.unwrap()).unwrap().font_size,
+        26.0
+    );
+
+    // Changes that don't affect the texture don't require a rebuild
+    assert!(!ctx.rebuild_font_atlas(|atlas| {
+        atlas.get_font_mut(default_font).unwrap().set_scale(1.5);
+    }));
+
+    let ui = ctx.new_frame();
+    let font = ui.push_font(large_font.unwrap());
+    assert_eq!(ui.current_font_size(), 26.0);
+    font.pop();
+    let _ = ctx.render();
+}
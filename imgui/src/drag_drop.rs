@@ -188,7 +188,9 @@ impl<'ui, T: AsRef<str>> DragDropSource<'ui, T> {
     /// If you want to pass a simple integer or other "plain old data", take a look at
     /// [begin_payload](Self::begin_payload).
     #[inline]
+    #[track_caller]
     pub fn begin(self) -> Option<DragDropSourceToolTip<'ui>> {
+        self.ui.assert_in_frame("DragDropSource::begin");
         self.begin_payload(())
     }
 
@@ -235,10 +237,12 @@ impl<'ui, T: AsRef<str>> DragDropSource<'ui, T> {
     /// }
     /// ```
     #[inline]
+    #[track_caller]
     pub fn begin_payload<P: Copy + 'static>(
         self,
         payload: P,
     ) -> Option<DragDropSourceToolTip<'ui>> {
+        self.ui.assert_in_frame("DragDropSource::begin_payload");
         unsafe {
             let payload = TypedPayload::new(payload);
             self.begin_payload_unchecked(
@@ -275,17 +279,20 @@ impl<'ui, T: AsRef<str>> DragDropSource<'ui, T> {
     /// Overall, users should be very sure that this function is needed before they reach for it, and instead
     /// should consider either [begin](Self::begin) or [begin_payload](Self::begin_payload).
     #[inline]
+    #[track_caller]
     pub unsafe fn begin_payload_unchecked(
         &self,
         ptr: *const ffi::c_void,
         size: usize,
     ) -> Option<DragDropSourceToolTip<'ui>> {
+        self.ui
+            .assert_in_frame("DragDropSource::begin_payload_unchecked");
         let should_begin = sys::igBeginDragDropSource(self.flags.bits() as i32);
 
         if should_begin {
             sys::igSetDragDropPayload(self.ui.scratch_txt(&self.name), ptr, size, self.cond as i32);
 
-            Some(DragDropSourceToolTip::push())
+            Some(DragDropSourceToolTip::push(self.ui))
         } else {
             None
         }
@@ -295,10 +302,10 @@ impl<'ui, T: AsRef<str>> DragDropSource<'ui, T> {
 /// A helper struct for RAII drap-drop support.
 pub struct DragDropSourceToolTip<'ui>(PhantomData<&'ui Ui>);
 
-impl DragDropSourceToolTip<'_> {
+impl<'ui> DragDropSourceToolTip<'ui> {
     /// Creates a new tooltip internally.
     #[inline]
-    fn push() -> Self {
+    fn push(_: &'ui Ui) -> Self {
         Self(PhantomData)
     }
 
@@ -320,7 +327,9 @@ impl Ui {
     /// Creates a new DragDropTarget, which gives methods for handling
     /// accepting payloads.
     #[doc(alias = "BeginDragDropTarget")]
+    #[track_caller]
     pub fn drag_drop_target(&self) -> Option<DragDropTarget<'_>> {
+        self.assert_in_frame("Ui::drag_drop_target");
         let should_begin = unsafe { sys::igBeginDragDropTarget() };
         if should_begin {
             Some(DragDropTarget(self))
@@ -328,6 +337,19 @@ impl Ui {
             None
         }
     }
+
+    /// Returns true if a drag and drop payload is being dropped on a target this frame.
+    ///
+    /// The delivery frame is the frame in which the mouse button is released over a target
+    /// that accepted the payload on the previous frame. The payload is cleared at the end of
+    /// that frame. Returns false if no drag and drop is in progress.
+    #[doc(alias = "IsDelivery")]
+    pub fn drag_drop_payload_is_delivery(&self) -> bool {
+        unsafe {
+            let payload = sys::igGetDragDropPayload();
+            !payload.is_null() && (*payload).Delivery
+        }
+    }
 }
 
 /// Creates a target for drag drop data out of the last ID created.
@@ -370,7 +392,9 @@ impl<'ui> DragDropTarget<'ui> {
     /// accepting payloads.
     #[doc(alias = "BeginDragDropTarget")]
     #[deprecated(since = "0.9.0", note = "Use `ui.drag_drop_target() instead")]
+    #[track_caller]
     pub fn new(ui: &'ui Ui) -> Option<Self> {
+        ui.assert_in_frame("DragDropTarget::new");
         ui.drag_drop_target()
     }
 
@@ -380,11 +404,14 @@ impl<'ui> DragDropTarget<'ui> {
     ///
     /// Note: If you began this operation with `begin_payload_unchecked` it always incorrect
     /// to use this function. Use `accept_payload_unchecked` instead
+    #[track_caller]
     pub fn accept_payload_empty(
         &self,
         name: impl AsRef<str>,
         flags: DragDropFlags,
     ) -> Option<DragDropPayloadEmpty> {
+        self.0
+            .assert_in_frame("DragDropTarget::accept_payload_empty");
         self.accept_payload(name, flags)?
             .ok()
             .map(|payload_pod: DragDropPayloadPod<()>| DragDropPayloadEmpty {
@@ -398,11 +425,13 @@ impl<'ui> DragDropTarget<'ui> {
     ///
     /// Note: If you began this operation with `begin_payload_unchecked` it always incorrect
     /// to use this function. Use `accept_payload_unchecked` instead
+    #[track_caller]
     pub fn accept_payload<T: 'static + Copy, Name: AsRef<str>>(
         &self,
         name: Name,
         flags: DragDropFlags,
     ) -> Option<Result<DragDropPayloadPod<T>, PayloadIsWrongType>> {
+        self.0.assert_in_frame("DragDropTarget::accept_payload");
         let output = unsafe { self.accept_payload_unchecked(name, flags) };
 
         // convert the unsafe payload to our Result
@@ -430,6 +459,24 @@ impl<'ui> DragDropTarget<'ui> {
         })
     }
 
+    /// Inspects a payload with plain old data in it while it's being dragged over the target,
+    /// e.g. to highlight where it would be inserted.
+    ///
+    /// This is [`accept_payload`](Self::accept_payload) with [`DragDropFlags::ACCEPT_PEEK_ONLY`]:
+    /// it returns the payload on every frame the target is hovered, with `delivery` set to
+    /// false, and doesn't draw the default highlight rectangle. On the frame the mouse button
+    /// is released over the target, it returns the payload with `delivery` set to true, which
+    /// is when the drop should be applied.
+    #[track_caller]
+    pub fn accept_payload_peek<T: 'static + Copy, Name: AsRef<str>>(
+        &self,
+        name: Name,
+    ) -> Option<Result<DragDropPayloadPod<T>, PayloadIsWrongType>> {
+        self.0
+            .assert_in_frame("DragDropTarget::accept_payload_peek");
+        self.accept_payload(name, DragDropFlags::ACCEPT_PEEK_ONLY)
+    }
+
     /// Accepts a drag and drop payload  which contains a raw pointer to [c_void](std::ffi::c_void)
     /// and a size in bytes. Users should generally avoid using this function
     /// if one of the safer variants is acceptable.
@@ -451,11 +498,14 @@ impl<'ui> DragDropTarget<'ui> {
     /// lead to all sorts of unsafe behvaior on ImGui's side. In summary, using this function for any data
     /// which isn't truly `Copy` or "plain old data" is difficult, and requires substantial knowledge
     /// of the various edge cases.
+    #[track_caller]
     pub unsafe fn accept_payload_unchecked(
         &self,
         name: impl AsRef<str>,
         flags: DragDropFlags,
     ) -> Option<DragDropPayload> {
+        self.0
+            .assert_in_frame("DragDropTarget::accept_payload_unchecked");
         let inner = sys::igAcceptDragDropPayload(self.0.scratch_txt(name), flags.bits() as i32);
         if inner.is_null() {
             None
@@ -611,3 +661,134 @@ impl std::fmt::Display for PayloadIsWrongType {
 }
 
 impl std::error::Error for PayloadIsWrongType {}
+
+#[test]
+fn test_accept_payload_peek_before_delivery() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.io_mut().config_input_trickle_event_queue = false;
+    let source_center = std::cell::Cell::new([0.0, 0.0]);
+    let target_center = std::cell::Cell::new([0.0, 0.0]);
+    let frame = |ctx: &mut crate::Context| {
+        let ui = ctx.new_frame();
+        let mut peeked = None;
+        let mut delivery = false;
+        ui.window("Drag")
+            .position([0.0, 0.0], Condition::Always)
+            .size([300.0, 300.0], Condition::Always)
+            .build(|| {
+                ui.button("Source");
+                source_center.set(item_center(ui));
+                if let Some(tooltip) = ui.drag_drop_source_config("ITEM").begin_payload(7u32) {
+                    tooltip.end();
+                }
+                ui.button_with_size("Target", [100.0, 100.0]);
+                target_center.set(item_center(ui));
+                if let Some(target) = ui.drag_drop_target() {
+                    peeked = target.accept_payload_peek::<u32, _>("ITEM");
+                    delivery = ui.drag_drop_payload_is_delivery();
+                }
+            });
+        let _ = ctx.render();
+        (peeked.map(|payload| payload.unwrap()), delivery)
+    };
+    fn item_center(ui: &Ui) -> [f32; 2] {
+        let (min, max) = (ui.item_rect_min(), ui.item_rect_max());
+        [(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0]
+    }
+
+    frame(&mut ctx);
+    frame(&mut ctx);
+    ctx.io_mut().add_mouse_pos_event(source_center.get());
+    ctx.io_mut()
+        .add_mouse_button_event(crate::MouseButton::Left, true);
+    assert!(frame(&mut ctx).0.is_none());
+
+    let mut previews = Vec::new();
+    for _ in 0..3 {
+        ctx.io_mut().add_mouse_pos_event(target_center.get());
+        previews.push(frame(&mut ctx));
+    }
+    let (payload, delivery) = previews.last().unwrap();
+    let payload = payload.unwrap();
+    assert_eq!(payload.data, 7);
+    assert!(payload.preview);
+    assert!(!payload.delivery);
+    assert!(!delivery);
+
+    ctx.io_mut()
+        .add_mouse_button_event(crate::MouseButton::Left, false);
+    let (payload, delivery) = frame(&mut ctx);
+    assert!(payload.unwrap().delivery);
+    assert!(delivery);
+
+    // The payload is gone after delivery
+    let (payload, delivery) = frame(&mut ctx);
+    assert!(payload.is_none());
+    assert!(!delivery);
+}
+
+#[test]
+fn test_drag_drop_flags_values() {
+    assert_eq!(
+        DragDropFlags::SOURCE_NO_PREVIEW_TOOLTIP.bits(),
+        sys::ImGuiDragDropFlags_SourceNoPreviewTooltip
+    );
+    assert_eq!(
+        DragDropFlags::SOURCE_ALLOW_NULL_ID.bits(),
+        sys::ImGuiDragDropFlags_SourceAllowNullID
+    );
+    assert_eq!(
+        DragDropFlags::SOURCE_EXTERN.bits(),
+        sys::ImGuiDragDropFlags_SourceExtern
+    );
+    assert_eq!(
+        DragDropFlags::ACCEPT_BEFORE_DELIVERY.bits(),
+        sys::ImGuiDragDropFlags_AcceptBeforeDelivery
+    );
+    assert_eq!(
+        DragDropFlags::ACCEPT_NO_DRAW_DEFAULT_RECT.bits(),
+        sys::ImGuiDragDropFlags_AcceptNoDrawDefaultRect
+    );
+    assert_eq!(
+        DragDropFlags::ACCEPT_PEEK_ONLY,
+        DragDropFlags::ACCEPT_BEFORE_DELIVERY | DragDropFlags::ACCEPT_NO_DRAW_DEFAULT_RECT
+    );
+}
+
+#[test]
+fn test_drag_drop_source_allow_null_id() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.io_mut().config_input_trickle_event_queue = false;
+    let canvas_center = std::cell::Cell::new([0.0, 0.0]);
+    let frame = |ctx: &mut crate::Context| {
+        let ui = ctx.new_frame();
+        let mut dragging = false;
+        ui.window("Canvas")
+            .position([0.0, 0.0], Condition::Always)
+            .size([300.0, 300.0], Condition::Always)
+            .build(|| {
+                // A region without an id, like a custom canvas
+                ui.dummy([100.0, 100.0]);
+                let (min, max) = (ui.item_rect_min(), ui.item_rect_max());
+                canvas_center.set([(min[0] + max[0]) / 2.0, (min[1] + max[1]) / 2.0]);
+                dragging = ui
+                    .drag_drop_source_config("POINT")
+                    .flags(DragDropFlags::SOURCE_ALLOW_NULL_ID)
+                    .begin()
+                    .is_some();
+            });
+        let _ = ctx.render();
+        dragging
+    };
+
+    frame(&mut ctx);
+    frame(&mut ctx);
+    ctx.io_mut().add_mouse_pos_event(canvas_center.get());
+    ctx.io_mut()
+        .add_mouse_button_event(crate::MouseButton::Left, true);
+    frame(&mut ctx);
+    let center = canvas_center.get();
+    ctx.io_mut()
+        .add_mouse_pos_event([center[0] + 20.0, center[1]]);
+    assert!(frame(&mut ctx));
+}
@@ -24,10 +24,61 @@ bitflags! {
     }
 }
 
+#[cfg(feature = "freetype")]
+bitflags! {
+    /// Rasterizer flags for the FreeType font builder.
+    ///
+    /// Pass these to [`FontConfig::font_builder_flags`] with `.bits()`.
+    #[repr(transparent)]
+    pub struct FreeTypeBuilderFlags: u32 {
+        /// Disable hinting
+        const NO_HINTING = sys::ImGuiFreeTypeBuilderFlags_NoHinting;
+        /// Disable auto-hinter
+        const NO_AUTO_HINT = sys::ImGuiFreeTypeBuilderFlags_NoAutoHint;
+        /// Prefer auto-hinter over the font's native hinter
+        const FORCE_AUTO_HINT = sys::ImGuiFreeTypeBuilderFlags_ForceAutoHint;
+        /// Use a lighter hinting algorithm for gray-level modes
+        const LIGHT_HINTING = sys::ImGuiFreeTypeBuilderFlags_LightHinting;
+        /// Strong hinting algorithm that should only be used for monochrome output
+        const MONO_HINTING = sys::ImGuiFreeTypeBuilderFlags_MonoHinting;
+        /// Artificially embolden the font
+        const BOLD = sys::ImGuiFreeTypeBuilderFlags_Bold;
+        /// Artificially slant the font
+        const OBLIQUE = sys::ImGuiFreeTypeBuilderFlags_Oblique;
+        /// Disable anti-aliasing
+        const MONOCHROME = sys::ImGuiFreeTypeBuilderFlags_Monochrome;
+        /// Enable FreeType color-layered glyphs
+        const LOAD_COLOR = sys::ImGuiFreeTypeBuilderFlags_LoadColor;
+        /// Enable FreeType bitmap glyphs
+        const BITMAP = sys::ImGuiFreeTypeBuilderFlags_Bitmap;
+    }
+}
+
 /// A font identifier
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct FontId(pub(crate) *const Font);
 
+/// A custom rectangle identifier
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CustomRectId(pub(crate) i32);
+
+/// A custom rectangle packed into the font atlas texture
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FontAtlasCustomRect {
+    /// Width of the rectangle in pixels
+    pub width: u16,
+    /// Height of the rectangle in pixels
+    pub height: u16,
+    /// Horizontal position of the rectangle within the texture in pixels
+    pub x: u16,
+    /// Vertical position of the rectangle within the texture in pixels
+    pub y: u16,
+    /// Top-left texture coordinate of the rectangle
+    pub uv_min: [f32; 2],
+    /// Bottom-right texture coordinate of the rectangle
+    pub uv_max: [f32; 2],
+}
+
 /// A font atlas that builds a single texture
 #[repr(C)]
 pub struct FontAtlas {
@@ -115,6 +166,10 @@ impl FontAtlas {
         };
         FontId(raw_font as *const _)
     }
+    /// Returns the ids of all fonts in the atlas, in the order they were added.
+    ///
+    /// Font ids stay valid until the fonts are removed with [`clear_fonts`](Self::clear_fonts)
+    /// or [`clear`](Self::clear), also across rebuilds of the atlas.
     pub fn fonts(&self) -> Vec<FontId> {
         let mut result = Vec::new();
         unsafe {
@@ -124,6 +179,32 @@ impl FontAtlas {
         }
         result
     }
+    /// Returns an iterator over the ids and sizes in pixels of all fonts in the atlas, in the
+    /// order they were added, e.g. to let the user pick a font.
+    ///
+    /// See [`fonts`](Self::fonts) for how long the ids stay valid.
+    pub fn iter_fonts(&self) -> impl Iterator<Item = (FontId, f32)> + '_ {
+        self.fonts.as_slice().iter().map(move |&font| {
+            let font = unsafe { &*font };
+            (font.id(), self.font_size(font))
+        })
+    }
+    /// Returns the size of a font, which is only set on the font once the atlas is built
+    fn font_size(&self, font: &Font) -> f32 {
+        if font.font_size > 0.0 || self.config_data.Size == 0 {
+            return font.font_size;
+        }
+        let configs =
+            unsafe { slice::from_raw_parts(self.config_data.Data, self.config_data.Size as usize) };
+        configs
+            .iter()
+            .find(|config| std::ptr::eq(config.DstFont as *const Font, font))
+            .map_or(font.font_size, |config| config.SizePixels)
+    }
+    /// Returns the number of fonts in the atlas
+    pub fn font_count(&self) -> usize {
+        self.fonts.as_slice().len()
+    }
     pub fn get_font(&self, id: FontId) -> Option<&Font> {
         unsafe {
             for &font in self.fonts.as_slice() {
@@ -134,7 +215,107 @@ impl FontAtlas {
         }
         None
     }
-    /// Returns true if the font atlas has been built
+    pub fn get_font_mut(&mut self, id: FontId) -> Option<&mut Font> {
+        unsafe {
+            for &font in self.fonts.as_slice() {
+                if id == FontId(font) {
+                    return Some(&mut *font);
+                }
+            }
+        }
+        None
+    }
+    /// Registers a custom rectangle to be packed into the atlas texture.
+    ///
+    /// The atlas only reserves the space: after building the texture, look up the packed
+    /// position with [`get_custom_rect`](Self::get_custom_rect) and write your own pixels into
+    /// the texture data at that position before uploading it to the GPU.
+    #[doc(alias = "AddCustomRectRegular")]
+    pub fn add_custom_rect_regular(&mut self, width: u16, height: u16) -> CustomRectId {
+        assert!(width > 0 && height > 0, "custom rect must not be empty");
+        let index = unsafe {
+            sys::ImFontAtlas_AddCustomRectRegular(self.raw_mut(), width as c_int, height as c_int)
+        };
+        CustomRectId(index)
+    }
+    /// Registers a custom rectangle that is used as the glyph for `codepoint` in the given font.
+    ///
+    /// This makes it possible to draw custom images inline with text. Like
+    /// [`add_custom_rect_regular`](Self::add_custom_rect_regular) only space is reserved, so the
+    /// pixels must be written into the texture data after building it and before uploading it.
+    #[doc(alias = "AddCustomRectFontGlyph")]
+    pub fn add_custom_rect_font_glyph(
+        &mut self,
+        font: FontId,
+        codepoint: char,
+        width: u16,
+        height: u16,
+        advance_x: f32,
+        offset: [f32; 2],
+    ) -> CustomRectId {
+        assert!(width > 0 && height > 0, "custom rect must not be empty");
+        assert!(
+            self.get_font(font).is_some(),
+            "font does not belong to this atlas"
+        );
+        let index = unsafe {
+            sys::ImFontAtlas_AddCustomRectFontGlyph(
+                self.raw_mut(),
+                font.0 as *mut sys::ImFont,
+                codepoint as sys::ImWchar,
+                width as c_int,
+                height as c_int,
+                advance_x,
+                offset.into(),
+            )
+        };
+        CustomRectId(index)
+    }
+    /// Returns the packed position of a custom rectangle.
+    ///
+    /// Returns `None` if the atlas hasn't been built since the rectangle was added.
+    #[doc(alias = "GetCustomRectByIndex", alias = "CalcCustomRectUV")]
+    pub fn get_custom_rect(&self, id: CustomRectId) -> Option<FontAtlasCustomRect> {
+        if id.0 < 0 || id.0 >= self.custom_rects.Size {
+            return None;
+        }
+        unsafe {
+            let rect = self.custom_rects.Data.add(id.0 as usize);
+            if !sys::ImFontAtlasCustomRect_IsPacked(rect) {
+                return None;
+            }
+            let mut uv_min = sys::ImVec2::zero();
+            let mut uv_max = sys::ImVec2::zero();
+            sys::ImFontAtlas_CalcCustomRectUV(
+                self.raw() as *const sys::ImFontAtlas as *mut _,
+                rect,
+                &mut uv_min,
+                &mut uv_max,
+            );
+            let rect = &*rect;
+            Some(FontAtlasCustomRect {
+                width: rect.Width,
+                height: rect.Height,
+                x: rect.X,
+                y: rect.Y,
+                uv_min: uv_min.into(),
+                uv_max: uv_max.into(),
+            })
+        }
+    }
+    /// Returns the texture coordinate of a solid white pixel in the atlas texture.
+    ///
+    /// Custom draw list geometry can sample this texel to draw flat colors using the font
+    /// texture. Only valid after the atlas has been built.
+    #[doc(alias = "TexUvWhitePixel")]
+    pub fn white_pixel_uv(&self) -> [f32; 2] {
+        self.tex_uv_white_pixel
+    }
+    /// Returns true if the font atlas has been built.
+    ///
+    /// The atlas is built by [`build_rgba32_texture`](Self::build_rgba32_texture) or
+    /// [`build_alpha8_texture`](Self::build_alpha8_texture), and stays built after
+    /// [`clear_tex_data`](Self::clear_tex_data) and [`clear_input_data`](Self::clear_input_data).
     #[doc(alias = "IsBuilt")]
     pub fn is_built(&self) -> bool {
         unsafe { sys::ImFontAtlas_IsBuilt(self.raw() as *const sys::ImFontAtlas as *mut _) }
@@ -223,14 +404,21 @@ impl FontAtlas {
     }
     /// Clears output texture data.
     ///
-    /// Can be used to save RAM once the texture has been transferred to the GPU.
+    /// Can be used to save RAM once the texture has been uploaded to the GPU. The fonts and
+    /// their glyphs stay usable, but building a texture again rebuilds the whole atlas, so
+    /// don't combine this with [`clear_input_data`](Self::clear_input_data) if the texture may
+    /// have to be uploaded again (e.g. after losing the graphics device).
     #[doc(alias = "ClearTexData")]
     pub fn clear_tex_data(&mut self) {
         unsafe {
             sys::ImFontAtlas_ClearTexData(self.raw_mut());
         }
     }
-    /// Clears all the data used to build the textures and fonts
+    /// Clears all the data used to build the textures and fonts, such as the TTF data and the
+    /// custom rectangles.
+    ///
+    /// Safe to call once the atlas has been built, as the built fonts remain usable, but the
+    /// atlas can't be rebuilt afterwards and the fonts lose their configuration data.
     #[doc(alias = "ClearInputData")]
     pub fn clear_input_data(&mut self) {
         unsafe {
@@ -239,6 +427,61 @@ impl FontAtlas {
     }
 }
 
+#[test]
+fn test_font_atlas_iter_fonts() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let atlas = ctx.fonts();
+    assert_eq!(atlas.font_count(), 0);
+    assert_eq!(atlas.iter_fonts().count(), 0);
+    let ids: Vec<FontId> = [13.0, 20.0, 26.0]
+        .iter()
+        .map(|&size_pixels| {
+            atlas.add_font(&[FontSource::DefaultFontData {
+                config: Some(FontConfig {
+                    size_pixels,
+                    ..FontConfig::default()
+                }),
+            }])
+        })
+        .collect();
+    assert_eq!(atlas.font_count(), 3);
+    assert_eq!(
+        atlas.iter_fonts().collect::<Vec<_>>(),
+        vec![(ids[0], 13.0), (ids[1], 20.0), (ids[2], 26.0)]
+    );
+    // Ids stay valid across rebuilds
+    let before = atlas.iter_fonts().collect::<Vec<_>>();
+    atlas.build_rgba32_texture();
+    assert_eq!(atlas.fonts(), ids);
+    assert_eq!(atlas.iter_fonts().collect::<Vec<_>>(), before);
+}
+
+#[test]
+fn test_font_atlas_clear_after_build() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    assert!(!ctx.fonts().is_built());
+    let (width, height) = {
+        let texture = ctx.fonts().build_rgba32_texture();
+        (texture.width, texture.height)
+    };
+    assert!(width > 0 && height > 0);
+    assert!(ctx.fonts().is_built());
+
+    ctx.fonts().clear_tex_data();
+    assert!(ctx.fonts().tex_pixels_rgba32.is_null());
+    assert!(ctx.fonts().is_built());
+    ctx.fonts().clear_input_data();
+    assert!(ctx.fonts().is_built());
+
+    // The fonts can still be used to render
+    let io = ctx.io_mut();
+    io.display_size = [1024.0, 768.0];
+    io.delta_time = 1.0 / 60.0;
+    let ui = ctx.new_frame();
+    ui.text("Hello");
+    let _ = ctx.render();
+}
+
 #[test]
 #[cfg(test)]
 fn test_font_atlas_memory_layout() {
@@ -298,11 +541,19 @@ pub enum FontSource<'a> {
 pub struct FontConfig {
     /// Size in pixels for the rasterizer
     pub size_pixels: f32,
-    /// Horizontal oversampling
+    /// Horizontal oversampling.
+    ///
+    /// Rasterizes glyphs at a higher resolution for sub-pixel positioning. Defaults to 3; the
+    /// difference between 2 and 3 is minimal, so 2 can be used to save texture memory.
     pub oversample_h: i32,
-    /// Vertical oversampling
+    /// Vertical oversampling.
+    ///
+    /// Defaults to 1, since sub-pixel positions aren't used on the Y axis.
     pub oversample_v: i32,
-    /// Align every glyph to pixel boundary
+    /// Align every glyph to pixel boundary.
+    ///
+    /// Useful e.g. when merging a non-pixel aligned font with the default font. When enabled,
+    /// `oversample_h` and `oversample_v` can be set to 1.
     pub pixel_snap_h: bool,
     /// Extra spacing (in pixels) between glyphs
     pub glyph_extra_spacing: [f32; 2],
@@ -314,9 +565,14 @@ pub struct FontConfig {
     pub glyph_min_advance_x: f32,
     /// Maximum advance_x for glyphs
     pub glyph_max_advance_x: f32,
-    /// Settings for a custom font rasterizer if used
+    /// Settings for a custom font rasterizer if used.
+    ///
+    /// The meaning depends on the font builder, so leave this as 0 if unsure. With the
+    /// `freetype` feature enabled these are [`FreeTypeBuilderFlags`] bits.
     pub font_builder_flags: u32,
-    /// Brighten (>1.0) or darken (<1.0) font output
+    /// Brighten (>1.0) or darken (<1.0) font output.
+    ///
+    /// Defaults to 1.0. Brightening small fonts may make them more readable.
     pub rasterizer_multiply: f32,
     /// Explicitly specify the ellipsis character.
     ///
@@ -419,6 +675,76 @@ fn test_font_config_default() {
     );
 }
 
+#[test]
+fn test_font_config_applied() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let config = FontConfig {
+        size_pixels: 20.0,
+        oversample_h: 1,
+        oversample_v: 2,
+        pixel_snap_h: true,
+        font_builder_flags: 1,
+        rasterizer_multiply: 1.5,
+        ..FontConfig::default()
+    };
+    let font_id = ctx.fonts().add_font(&[FontSource::DefaultFontData {
+        config: Some(config),
+    }]);
+    let atlas = ctx.fonts();
+    let configs =
+        unsafe { slice::from_raw_parts(atlas.config_data.Data, atlas.config_data.Size as usize) };
+    let raw = configs.last().unwrap();
+    assert_eq!(raw.SizePixels, 20.0);
+    assert_eq!(raw.OversampleH, 1);
+    assert_eq!(raw.OversampleV, 2);
+    assert!(raw.PixelSnapH);
+    assert_eq!(raw.FontBuilderFlags, 1);
+    assert_eq!(raw.RasterizerMultiply, 1.5);
+    assert_eq!(raw.DstFont as *const Font, font_id.0);
+    let _ = atlas.build_rgba32_texture();
+    assert_eq!(atlas.get_font(font_id).unwrap().font_size, 20.0);
+}
+
+#[test]
+fn test_font_atlas_custom_rects() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let atlas = ctx.fonts();
+    let font_id = atlas.add_font(&[FontSource::DefaultFontData { config: None }]);
+    let icon = atlas.add_custom_rect_regular(16, 8);
+    let glyph = atlas.add_custom_rect_font_glyph(font_id, '\u{e000}', 12, 12, 13.0, [0.0, 1.0]);
+    assert_ne!(icon, glyph);
+    assert!(atlas.get_custom_rect(icon).is_none());
+
+    let texture = atlas.build_rgba32_texture();
+    let (tex_width, tex_height) = (texture.width, texture.height);
+    let rect = atlas.get_custom_rect(icon).unwrap();
+    assert_eq!((rect.width, rect.height), (16, 8));
+    assert!(u32::from(rect.x + rect.width) <= tex_width);
+    assert!(u32::from(rect.y + rect.height) <= tex_height);
+    let expected_u = f32::from(rect.x) / tex_width as f32;
+    assert!((rect.uv_min[0] - expected_u).abs() < 1e-6);
+    assert!(rect.uv_min[1] < rect.uv_max[1] && rect.uv_max[1] <= 1.0);
+    assert!(atlas.get_custom_rect(glyph).is_some());
+
+    let glyph = atlas
+        .get_font(font_id)
+        .unwrap()
+        .find_glyph('\u{e000}')
+        .unwrap();
+    assert_eq!(glyph.advance_x, 13.0);
+    assert_eq!(glyph.x1 - glyph.x0, 12.0);
+}
+
+#[test]
+fn test_font_atlas_white_pixel_uv() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let [u, v] = ctx.fonts().white_pixel_uv();
+    assert!((0.0..=1.0).contains(&u));
+    assert!((0.0..=1.0).contains(&v));
+    let ui = ctx.new_frame();
+    assert_eq!(ui.font_tex_uv_white_pixel(), [u, v]);
+}
+
 /// Handle to a font atlas texture
 #[derive(Clone, Debug)]
 pub struct FontAtlasTexture<'a> {
@@ -35,6 +35,86 @@ impl Font {
     pub fn id(&self) -> FontId {
         FontId(self as *const _)
     }
+    /// Returns the base scale of this font
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+    /// Sets the base scale of this font (default is 1.0).
+    ///
+    /// It is multiplied with `Io::font_global_scale` and the per-window font scale. Like the
+    /// global scale, this stretches the rasterized glyphs, so large values look blurry.
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale;
+    }
+    /// Returns the character used in place of glyphs missing from this font
+    pub fn fallback_char(&self) -> char {
+        std::char::from_u32(self.fallback_char).unwrap_or(std::char::REPLACEMENT_CHARACTER)
+    }
+    /// Returns the glyph for the given character, or `None` if this font doesn't contain it.
+    ///
+    /// The font atlas must have been built, otherwise no glyphs are available.
+    #[doc(alias = "FindGlyphNoFallback")]
+    pub fn find_glyph(&self, c: char) -> Option<FontGlyph> {
+        unsafe {
+            let glyph = sys::ImFont_FindGlyphNoFallback(self.raw() as *const _ as *mut _, c as _);
+            (glyph as *const FontGlyph).as_ref().copied()
+        }
+    }
+    /// Returns the glyph for the given character, or the glyph of the
+    /// [fallback character](Self::fallback_char) if this font doesn't contain it.
+    ///
+    /// Returns `None` only if the fallback glyph is missing too, e.g. because the font atlas
+    /// hasn't been built yet.
+    #[doc(alias = "FindGlyph")]
+    pub fn find_glyph_or_fallback(&self, c: char) -> Option<FontGlyph> {
+        unsafe {
+            let glyph = sys::ImFont_FindGlyph(self.raw() as *const _ as *mut _, c as _);
+            (glyph as *const FontGlyph).as_ref().copied()
+        }
+    }
+}
+
+#[test]
+fn test_font_scale() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let font_id = ctx.fonts().fonts()[0];
+    let base_size = ctx.fonts().get_font(font_id).unwrap().font_size;
+    assert_eq!(ctx.io().font_global_scale(), 1.0);
+    ctx.io_mut().set_font_global_scale(2.0);
+    {
+        let ui = ctx.new_frame();
+        ui.window("Scaled").build(|| {
+            assert_eq!(ui.current_font_size(), base_size * 2.0);
+        });
+        let _ = ctx.render();
+    }
+    ctx.fonts().get_font_mut(font_id).unwrap().set_scale(0.5);
+    assert_eq!(ctx.fonts().get_font(font_id).unwrap().scale(), 0.5);
+    {
+        let ui = ctx.new_frame();
+        ui.window("Scaled").build(|| {
+            assert_eq!(ui.current_font_size(), base_size);
+        });
+        let _ = ctx.render();
+    }
+}
+
+#[test]
+fn test_font_find_glyph() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let font_id = ctx.fonts().fonts()[0];
+    let font = ctx.fonts().get_font(font_id).unwrap();
+    let glyph = font.find_glyph('A').unwrap();
+    assert_eq!(glyph.codepoint(), 'A' as u32);
+    assert!(glyph.visible());
+    assert!(glyph.advance_x > 0.0);
+    assert!(glyph.x1 > glyph.x0 && glyph.y1 > glyph.y0);
+    assert!(glyph.u1 > glyph.u0 && glyph.v1 > glyph.v0);
+
+    // The default font only covers basic latin
+    assert!(font.find_glyph('\u{4e2d}').is_none());
+    let fallback = font.find_glyph_or_fallback('\u{4e2d}').unwrap();
+    assert_eq!(fallback.codepoint(), font.fallback_char() as u32);
 }
 
 #[test]
@@ -6,14 +6,23 @@ use crate::sys;
 #[repr(C)]
 pub struct FontGlyph {
     bitfields: u32,
+    /// Distance to the next character
     pub advance_x: f32,
+    /// Left edge of the glyph quad
     pub x0: f32,
+    /// Top edge of the glyph quad
     pub y0: f32,
+    /// Right edge of the glyph quad
     pub x1: f32,
+    /// Bottom edge of the glyph quad
     pub y1: f32,
+    /// Left texture coordinate
     pub u0: f32,
+    /// Top texture coordinate
     pub v0: f32,
+    /// Right texture coordinate
     pub u1: f32,
+    /// Bottom texture coordinate
     pub v1: f32,
 }
 
@@ -1,3 +1,4 @@
+use crate::fonts::atlas::FontId;
 use crate::fonts::font::Font;
 use crate::internal::RawCast;
 use crate::Ui;
@@ -14,6 +15,22 @@ impl Ui {
     pub fn current_font(&self) -> &Font {
         unsafe { Font::from_raw(&*sys::igGetFont()) }
     }
+    /// Returns the id of the current font, e.g. to compare it with the fonts of the atlas or to
+    /// push it again later.
+    pub fn current_font_id(&self) -> FontId {
+        self.current_font().id()
+    }
+    /// Returns the id of the default font, which is used when no font is pushed.
+    ///
+    /// This is the first font added to the atlas.
+    pub fn default_font(&self) -> FontId {
+        let default_font = self.io().font_default;
+        if default_font.is_null() {
+            self.fonts().fonts()[0]
+        } else {
+            FontId(default_font)
+        }
+    }
     /// Returns the current font size (= height in pixels) with font scale applied
     #[doc(alias = "GetFontSize")]
     pub fn current_font_size(&self) -> f32 {
@@ -34,3 +51,29 @@ impl Ui {
         unsafe { sys::igSetWindowFontScale(scale) }
     }
 }
+
+#[test]
+fn test_default_and_current_font_ids() {
+    use crate::fonts::atlas::{FontConfig, FontSource};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let large_font = ctx.fonts().add_font(&[FontSource::DefaultFontData {
+        config: Some(FontConfig {
+            size_pixels: 26.0,
+            ..FontConfig::default()
+        }),
+    }]);
+    ctx.fonts().build_rgba32_texture();
+    let first_font = ctx.fonts().fonts()[0];
+    let ui = ctx.new_frame();
+    let default_font = ui.default_font();
+    assert!(!default_font.0.is_null());
+    assert_eq!(default_font, first_font);
+    assert_eq!(ui.current_font_id(), default_font);
+    let token = ui.push_font(large_font);
+    assert_eq!(ui.current_font_id(), large_font);
+    assert_eq!(ui.default_font(), default_font);
+    token.pop();
+    assert_eq!(ui.current_font_id(), default_font);
+    let _ = ctx.render();
+}
@@ -0,0 +1,97 @@
+use crate::context::Context;
+use crate::{DrawCmd, TextureId, Ui};
+
+/// A draw command recorded by [`headless`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadlessCmd {
+    /// Clip rectangle, as `[min_x, min_y, max_x, max_y]` in display coordinates
+    pub clip_rect: [f32; 4],
+    /// Texture to draw with, [`HEADLESS_FONT_TEXTURE`] for the font atlas
+    pub texture_id: TextureId,
+    /// Number of indices drawn by the command
+    pub count: usize,
+}
+
+/// Summary of the draw data produced by [`headless`]
+#[derive(Debug, Clone, Default)]
+pub struct HeadlessFrame {
+    /// Total number of vertices of all draw lists
+    pub vtx_count: usize,
+    /// Total number of indices of all draw lists
+    pub idx_count: usize,
+    /// Element draw commands of all draw lists, in rendering order
+    pub cmds: Vec<HeadlessCmd>,
+}
+
+/// Font atlas texture id used by [`headless`]
+pub const HEADLESS_FONT_TEXTURE: TextureId = TextureId::new(1);
+
+/// Builds the UI with `f` for a couple of frames on a fresh context (so auto-sized windows
+/// settle), without any renderer, and summarizes the draw data of the last frame.
+///
+/// The display is 1024x768, and the mouse is outside of it. This can be used to check UI
+/// layout in CI, where there is no GPU.
+///
+/// # Panics
+///
+/// Panics if an active context already exists
+pub fn headless(mut f: impl FnMut(&Ui)) -> HeadlessFrame {
+    let mut ctx = Context::create();
+    ctx.set_ini_filename(None);
+    let io = ctx.io_mut();
+    io.display_size = [1024.0, 768.0];
+    io.delta_time = 1.0 / 60.0;
+    let fonts = ctx.fonts();
+    fonts.build_rgba32_texture();
+    fonts.tex_id = HEADLESS_FONT_TEXTURE;
+    let mut frame = HeadlessFrame::default();
+    for _ in 0..2 {
+        f(ctx.new_frame());
+        let draw_data = ctx.render();
+        frame = HeadlessFrame {
+            vtx_count: draw_data.total_vtx_count as usize,
+            idx_count: draw_data.total_idx_count as usize,
+            cmds: Vec::new(),
+        };
+        for draw_list in draw_data.draw_lists() {
+            for cmd in draw_list.commands() {
+                if let DrawCmd::Elements { count, cmd_params } = cmd {
+                    frame.cmds.push(HeadlessCmd {
+                        clip_rect: cmd_params.clip_rect,
+                        texture_id: cmd_params.texture_id,
+                        count,
+                    });
+                }
+            }
+        }
+    }
+    frame
+}
+
+#[test]
+fn test_headless_window_draw_commands() {
+    let empty = crate::test::headless(|_| {});
+    assert_eq!(empty.vtx_count, 0);
+    assert!(empty.cmds.is_empty());
+
+    let frame = crate::test::headless(|ui| {
+        ui.window("Headless")
+            .position([10.0, 20.0], crate::Condition::Always)
+            .size([200.0, 100.0], crate::Condition::Always)
+            .build(|| ui.text("Hello"));
+    });
+    assert!(frame.vtx_count > 0);
+    assert_eq!(
+        frame.idx_count,
+        frame.cmds.iter().map(|cmd| cmd.count).sum()
+    );
+    // The window decorations clipped to the display, then its contents clipped to the window
+    assert_eq!(frame.cmds.len(), 2, "{:?}", frame.cmds);
+    assert_eq!(frame.cmds[0].clip_rect, [0.0, 0.0, 1024.0, 768.0]);
+    let [x0, y0, x1, y1] = frame.cmds[1].clip_rect;
+    assert!(x0 > 10.0 && y0 > 20.0 && x1 <= 210.0 && y1 <= 120.0);
+    assert!(frame
+        .cmds
+        .iter()
+        .all(|cmd| cmd.texture_id == HEADLESS_FONT_TEXTURE));
+}
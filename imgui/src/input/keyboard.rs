@@ -1,4 +1,8 @@
+#[cfg(feature = "docking")]
+use bitflags::bitflags;
+
 use crate::sys;
+use crate::NavInput;
 use crate::Ui;
 
 /// A key identifier
@@ -302,6 +306,63 @@ impl Key {
     pub const COUNT: usize = sys::ImGuiKey_NamedKey_COUNT as usize;
 }
 
+/// A key combined with modifiers, e.g. Ctrl+S, used by [`Ui::shortcut`]
+#[cfg(feature = "docking")]
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct KeyChord(u32);
+
+#[cfg(feature = "docking")]
+impl KeyChord {
+    /// Creates a chord for a key without modifiers
+    pub const fn new(key: Key) -> KeyChord {
+        KeyChord(key as u32)
+    }
+    /// Adds a modifier ([`Key::ModCtrl`], [`Key::ModShift`], [`Key::ModAlt`], [`Key::ModSuper`]
+    /// or [`Key::ModShortcut`]) to the chord.
+    ///
+    /// [`Key::ModShortcut`] is Ctrl, or Cmd with
+    /// [`Io::config_mac_os_behaviors`](crate::Io::config_mac_os_behaviors).
+    pub fn with_mod(self, modifier: Key) -> KeyChord {
+        assert!(
+            modifier as u32 & sys::ImGuiMod_Mask_ == modifier as u32,
+            "{:?} is not a modifier",
+            modifier
+        );
+        KeyChord(self.0 | modifier as u32)
+    }
+}
+
+#[cfg(feature = "docking")]
+impl From<Key> for KeyChord {
+    fn from(key: Key) -> KeyChord {
+        KeyChord::new(key)
+    }
+}
+
+#[cfg(feature = "docking")]
+bitflags! {
+    /// Options for [`Ui::shortcut`]
+    #[repr(transparent)]
+    pub struct InputFlags: u32 {
+        /// Fire again while the chord is held, at the key repeat rate
+        const REPEAT = sys::ImGuiInputFlags_Repeat;
+        /// Only fire when the current window is focused or one of its parents is (the default)
+        const ROUTE_FOCUSED = sys::ImGuiInputFlags_RouteFocused;
+        /// Fire regardless of focus, unless a focused window also registered the chord
+        const ROUTE_GLOBAL_LOW = sys::ImGuiInputFlags_RouteGlobalLow;
+        /// Fire regardless of focus, unless a focused window with an active item also
+        /// registered the chord
+        const ROUTE_GLOBAL = sys::ImGuiInputFlags_RouteGlobal;
+        /// Fire regardless of focus, taking priority over focused windows
+        const ROUTE_GLOBAL_HIGH = sys::ImGuiInputFlags_RouteGlobalHigh;
+        /// Always fire, bypassing routing entirely
+        const ROUTE_ALWAYS = sys::ImGuiInputFlags_RouteAlways;
+        /// Combined with a global route: don't fire while no Dear ImGui window is focused, i.e.
+        /// while the application has focus
+        const ROUTE_UNLESS_BG_FOCUSED = sys::ImGuiInputFlags_RouteUnlessBgFocused;
+    }
+}
+
 /// Target widget selection for keyboard focus
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
 pub enum FocusedWidget {
@@ -473,4 +534,76 @@ impl Ui {
             sys::igSetKeyboardFocusHere(target_widget.as_offset());
         }
     }
+
+    /// Returns true if the key chord was pressed this frame, and this call owns its route.
+    ///
+    /// When several places register the same chord, routing decides which one fires, based on
+    /// the route flag in `flags` (at most one, [`InputFlags::ROUTE_FOCUSED`] by default) and on
+    /// which window is focused. For example, a `Ctrl+S` registered with
+    /// [`InputFlags::ROUTE_GLOBAL`] fires even when no window is focused, unless the focused
+    /// window handles `Ctrl+S` itself.
+    ///
+    /// Routes are resolved at the start of each frame from the calls of the previous frame, so
+    /// this must be called every frame, and only starts firing on the second one.
+    ///
+    /// This version of Dear ImGui has no `SetNextItemShortcut`: to trigger a widget with a
+    /// chord, check this and act as if the widget was used.
+    ///
+    /// **Unstable**, see [Unstable APIs](crate#unstable-apis).
+    #[cfg(feature = "docking")]
+    #[doc(alias = "Shortcut")]
+    pub fn shortcut(&self, chord: impl Into<KeyChord>, flags: InputFlags) -> bool {
+        let route = flags.bits() & sys::ImGuiInputFlags_RouteMask_;
+        assert!(
+            route.count_ones() <= 1,
+            "at most one route flag can be passed to Ui::shortcut"
+        );
+        unsafe { sys::igShortcut(chord.into().0 as _, 0, flags.bits() as _) }
+    }
+
+    /// Returns the analog amount (0.0 - 1.0) of a navigation input for the current frame.
+    ///
+    /// This reads the values the backend wrote to [`Io::nav_inputs`](crate::Io::nav_inputs),
+    /// so it returns 0.0 for backends that feed gamepad input through key events instead.
+    #[inline]
+    pub fn nav_input_amount(&self, input: NavInput) -> f32 {
+        self.io()[input]
+    }
+}
+
+#[cfg(feature = "docking")]
+#[test]
+fn test_global_shortcut_fires_without_focus() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.io_mut().config_input_trickle_event_queue = false;
+    let save = KeyChord::new(Key::S).with_mod(Key::ModCtrl);
+    let mut fired = Vec::new();
+    for frame in 0..4 {
+        if frame == 2 {
+            ctx.io_mut().add_key_event(Key::ModCtrl, true);
+            ctx.io_mut().add_key_event(Key::S, true);
+        }
+        let ui = ctx.new_frame();
+        let global = ui.shortcut(save, InputFlags::ROUTE_GLOBAL);
+        // Nothing has focus, so focused routes never fire
+        let mut focused = false;
+        ui.window("Editor")
+            .focus_on_appearing(false)
+            .build(|| focused = ui.shortcut(save, InputFlags::empty()));
+        // Ctrl only isn't Ctrl+S
+        let plain = ui.shortcut(Key::S, InputFlags::ROUTE_ALWAYS);
+        fired.push((global, focused, plain));
+        let _ = ctx.render();
+    }
+    assert_eq!(fired[2], (true, false, false));
+    // Held, but without REPEAT it only fires once
+    assert_eq!(fired[3], (false, false, false));
+    assert!(fired[..2].iter().all(|&f| f == (false, false, false)));
+}
+
+#[cfg(feature = "docking")]
+#[test]
+#[should_panic(expected = "is not a modifier")]
+fn test_key_chord_rejects_non_modifiers() {
+    let _ = KeyChord::new(Key::S).with_mod(Key::A);
 }
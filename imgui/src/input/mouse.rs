@@ -152,12 +152,34 @@ impl Ui {
     ///
     /// Clipped by current clipping settings, but disregards other factors like focus, window
     /// ordering, modal popup blocking.
+    ///
+    /// This is the same as [is_mouse_hovering_rect_with_clip](Self::is_mouse_hovering_rect_with_clip)
+    /// with `clip` set to `true`.
+    #[doc(alias = "IsMouseHoveringRect")]
     pub fn is_mouse_hovering_rect(
         &self,
         r_min: impl Into<MintVec2>,
         r_max: impl Into<MintVec2>,
     ) -> bool {
-        unsafe { sys::igIsMouseHoveringRect(r_min.into().into(), r_max.into().into(), true) }
+        self.is_mouse_hovering_rect_with_clip(r_min, r_max, true)
+    }
+    /// Returns true if the mouse is hovering over the given bounding rect, in screen
+    /// coordinates.
+    ///
+    /// If `clip` is true, the rect is first intersected with the current clip rect (e.g. the
+    /// visible part of the current window), so parts that are scrolled out of view or outside
+    /// the window don't count. If it is false, the rect is tested as is. Either way, this
+    /// disregards other factors like focus, window ordering, modal popup blocking; use
+    /// [`is_window_hovered`](Self::is_window_hovered) to check those. The rect is slightly
+    /// extended by `Style::touch_extra_padding`.
+    #[doc(alias = "IsMouseHoveringRect")]
+    pub fn is_mouse_hovering_rect_with_clip(
+        &self,
+        r_min: impl Into<MintVec2>,
+        r_max: impl Into<MintVec2>,
+        clip: bool,
+    ) -> bool {
+        unsafe { sys::igIsMouseHoveringRect(r_min.into().into(), r_max.into().into(), clip) }
     }
     /// Returns the mouse position backed up at the time of opening a popup
     #[doc(alias = "GetMousePosOnOpeningCurrentPopup")]
@@ -239,10 +261,27 @@ impl Ui {
             );
         }
     }
+    /// Returns the mouse position in screen coordinates, as seen by Dear ImGui this frame.
+    ///
+    /// This is `[-f32::MAX, -f32::MAX]` when the mouse position is unavailable, e.g. because the
+    /// cursor left the application window; check with
+    /// [`is_current_mouse_pos_valid`](Self::is_current_mouse_pos_valid) before using it.
+    #[doc(alias = "GetMousePos")]
+    pub fn mouse_pos(&self) -> [f32; 2] {
+        let mut out = sys::ImVec2::zero();
+        unsafe { sys::igGetMousePos(&mut out) };
+        out.into()
+    }
+    /// Returns true if the current [mouse position](Self::mouse_pos) is available.
+    ///
+    /// Backends report an unavailable position as `[-f32::MAX, -f32::MAX]`; any coordinate
+    /// below `-256000.0` is treated as invalid.
     #[doc(alias = "IsMousePosValid")]
     pub fn is_current_mouse_pos_valid(&self) -> bool {
         unsafe { sys::igIsMousePosValid(ptr::null()) }
     }
+    /// Returns true if the given mouse position is valid, see
+    /// [`is_current_mouse_pos_valid`](Self::is_current_mouse_pos_valid).
     #[doc(alias = "IsMousePosValid")]
     pub fn is_mouse_pos_valid(&self, mouse_pos: impl Into<MintVec2>) -> bool {
         unsafe { sys::igIsMousePosValid(&mouse_pos.into().into()) }
@@ -526,3 +565,49 @@ fn test_mouse_drags() {
         }
     }
 }
+
+#[test]
+fn test_is_mouse_hovering_rect() {
+    fn hovering(mouse_pos: [f32; 2]) -> Vec<bool> {
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        ctx.io_mut().mouse_pos = mouse_pos;
+        let ui = ctx.new_frame();
+        let mut results = Vec::new();
+        ui.window("Canvas")
+            .position([0.0, 0.0], crate::Condition::Always)
+            .size([100.0, 100.0], crate::Condition::Always)
+            .build(|| {
+                results.push(ui.is_mouse_hovering_rect([40.0, 40.0], [60.0, 60.0]));
+                // Extends past the right edge of the window
+                let (min, max) = ([40.0, 40.0], [200.0, 60.0]);
+                results.push(ui.is_mouse_hovering_rect_with_clip(min, max, true));
+                results.push(ui.is_mouse_hovering_rect_with_clip(min, max, false));
+            });
+        let _ = ctx.render();
+        results
+    }
+    assert_eq!(hovering([50.0, 50.0]), [true, true, true]);
+    // Right of the window: only hovering when the rect isn't clipped to it
+    assert_eq!(hovering([150.0, 50.0]), [false, false, true]);
+    assert_eq!(hovering([150.0, 150.0]), [false, false, false]);
+}
+
+#[test]
+fn test_mouse_pos_validity() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.io_mut().mouse_pos = [12.0, 34.0];
+    {
+        let ui = ctx.new_frame();
+        assert_eq!(ui.mouse_pos(), [12.0, 34.0]);
+        assert!(ui.is_current_mouse_pos_valid());
+        assert!(ui.is_mouse_pos_valid([0.0, 0.0]));
+        assert!(!ui.is_mouse_pos_valid([-f32::MAX, -f32::MAX]));
+        let _ = ctx.render();
+    }
+    // The cursor left the window
+    ctx.io_mut().mouse_pos = [-f32::MAX, -f32::MAX];
+    let ui = ctx.new_frame();
+    assert_eq!(ui.mouse_pos(), [-f32::MAX, -f32::MAX]);
+    assert!(!ui.is_current_mouse_pos_valid());
+    let _ = ctx.render();
+}
@@ -54,9 +54,31 @@ bitflags!(
         const CHARS_SCIENTIFIC = sys::ImGuiInputTextFlags_CharsScientific;
         /// Allow buffer capacity resize + notify when the string wants to be resized
         const CALLBACK_RESIZE = sys::ImGuiInputTextFlags_CallbackResize;
+        /// Escape key clears the content if not empty, and deactivates otherwise (contrast to
+        /// the default behavior of escape reverting the edit)
+        const ESCAPE_CLEARS_ALL = sys::ImGuiInputTextFlags_EscapeClearsAll;
     }
 );
 
+#[test]
+fn test_input_text_flags_values() {
+    assert_eq!(InputTextFlags::CHARS_DECIMAL.bits(), 1 << 0);
+    assert_eq!(InputTextFlags::CHARS_HEXADECIMAL.bits(), 1 << 1);
+    assert_eq!(InputTextFlags::CHARS_UPPERCASE.bits(), 1 << 2);
+    assert_eq!(InputTextFlags::CHARS_NO_BLANK.bits(), 1 << 3);
+    assert_eq!(InputTextFlags::AUTO_SELECT_ALL.bits(), 1 << 4);
+    assert_eq!(InputTextFlags::ENTER_RETURNS_TRUE.bits(), 1 << 5);
+    assert_eq!(InputTextFlags::READ_ONLY.bits(), 1 << 14);
+    assert_eq!(InputTextFlags::PASSWORD.bits(), 1 << 15);
+    assert_eq!(InputTextFlags::NO_UNDO_REDO.bits(), 1 << 16);
+    assert_eq!(InputTextFlags::CALLBACK_RESIZE.bits(), 1 << 18);
+    assert_eq!(InputTextFlags::ESCAPE_CLEARS_ALL.bits(), 1 << 20);
+    assert_eq!(
+        (InputTextFlags::PASSWORD | InputTextFlags::CHARS_NO_BLANK).bits(),
+        sys::ImGuiInputTextFlags_Password | sys::ImGuiInputTextFlags_CharsNoBlank
+    );
+}
+
 macro_rules! impl_text_flags {
     ($InputType:ident) => {
         #[inline]
@@ -143,6 +165,12 @@ macro_rules! impl_text_flags {
             self.flags.set(InputTextFlags::NO_UNDO_REDO, value);
             self
         }
+
+        #[inline]
+        pub fn escape_clears_all(mut self, value: bool) -> Self {
+            self.flags.set(InputTextFlags::ESCAPE_CLEARS_ALL, value);
+            self
+        }
     };
 }
 
@@ -283,7 +311,9 @@ where
     /// your string.
     /// 3. Truncations by ImGui appear to be done primarily by insertions of `\0` to the truncation point.
     /// We will handle this for you and edit the string "properly" too, but this might show up in callbacks.
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("InputText::build");
         // needs to be null-terminated! this is a hack!
         self.buf.push('\0');
 
@@ -444,7 +474,9 @@ impl<'ui, 'p, T: InputTextCallbackHandler, L: AsRef<str>> InputTextMultiline<'ui
     /// your string.
     /// 3. Truncations by ImGui appear to be done primarily by insertions of `\0` to the truncation point.
     /// We will handle this for you and edit the string "properly" too, but this might show up in callbacks.
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("InputTextMultiline::build");
         // needs to be null-terminated! this is a hack!
         self.buf.push('\0');
         let (ptr, capacity) = (self.buf.as_mut_ptr(), self.buf.capacity());
@@ -517,7 +549,9 @@ impl<'ui, 'p, L: AsRef<str>> InputInt<'ui, 'p, L> {
         }
     }
 
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("InputInt::build");
         unsafe {
             sys::igInputInt(
                 self.ui.scratch_txt(self.label),
@@ -573,7 +607,9 @@ impl<'ui, 'p, L: AsRef<str>> InputFloat<'ui, 'p, L> {
         }
     }
 
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("InputFloat::build");
         let (one, two) = self
             .ui
             .scratch_txt_with_opt(self.label, self.display_format);
@@ -634,7 +670,13 @@ macro_rules! impl_input_floatn {
                 }
             }
 
+            /// Builds the widget, writing all components back to the value.
+            ///
+            /// Returns true if any component was edited.
+            #[track_caller]
             pub fn build(self) -> bool {
+                self.ui
+                    .assert_in_frame(concat!(stringify!($InputFloatN), "::build"));
                 let value: $MINT_TARGET = (*self.value).into();
                 let mut value: [f32; $N] = value.into();
 
@@ -688,7 +730,13 @@ macro_rules! impl_input_intn {
                 }
             }
 
+            /// Builds the widget, writing all components back to the value.
+            ///
+            /// Returns true if any component was edited.
+            #[track_caller]
             pub fn build(self) -> bool {
+                self.ui
+                    .assert_in_frame(concat!(stringify!($InputIntN), "::build"));
                 let value: $MINT_TARGET = (*self.value).into();
                 let mut value: [i32; $N] = value.into();
 
@@ -764,7 +812,9 @@ impl<'ui, 'p, L: AsRef<str>, T: DataTypeKind, F: AsRef<str>> InputScalar<'ui, 'p
     /// Builds an input scalar that is bound to the given value.
     ///
     /// Returns true if the value was changed.
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("InputScalar::build");
         unsafe {
             let (one, two) = self
                 .ui
@@ -850,7 +900,9 @@ impl<'ui, 'p, L: AsRef<str>, T: DataTypeKind, F: AsRef<str>> InputScalarN<'ui, '
     /// Builds a horizontal array of multiple input scalars attached to the given slice.
     ///
     /// Returns true if any value was changed.
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("InputScalarN::build");
         unsafe {
             let (one, two) = self
                 .ui
@@ -1295,3 +1347,41 @@ extern "C" fn callback<T: InputTextCallbackHandler>(
 /// actually run, since you will not have pass imgui any flags).
 pub struct PassthroughCallback;
 impl InputTextCallbackHandler for PassthroughCallback {}
+
+#[test]
+fn test_input_n_writes_all_components() {
+    use crate::FocusedWidget;
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut floats = [1.0f32, 2.0, 3.0];
+    let mut ints = [1i32, 2, 3];
+    // Focus each component in turn (which selects its text) and replace it with a new digit
+    for (widget, component, digit) in [
+        (0, 0, '7'),
+        (0, 1, '8'),
+        (0, 2, '9'),
+        (1, 0, '7'),
+        (1, 1, '8'),
+        (1, 2, '9'),
+    ] {
+        for frame in 0..3 {
+            if frame == 2 {
+                ctx.io_mut().add_input_character(digit);
+            }
+            let ui = ctx.new_frame();
+            ui.window("Inputs").build(|| {
+                if frame == 0 && widget == 0 {
+                    ui.set_keyboard_focus_here_with_offset(FocusedWidget::Offset(component));
+                }
+                ui.input_float3("floats", &mut floats).build();
+                if frame == 0 && widget == 1 {
+                    ui.set_keyboard_focus_here_with_offset(FocusedWidget::Offset(component));
+                }
+                ui.input_int3("ints", &mut ints).build();
+            });
+            let _ = ctx.render();
+        }
+    }
+    assert_eq!(floats, [7.0, 8.0, 9.0]);
+    assert_eq!(ints, [7, 8, 9]);
+}
@@ -123,6 +123,30 @@ impl NavInput {
     const INTERNAL_COUNT: usize = 0;
     /// Total count of `NavInput` variants
     pub const COUNT: usize = sys::ImGuiNavInput_COUNT as usize - NavInput::INTERNAL_COUNT;
+
+    /// Returns the gamepad key this navigation input is mapped to.
+    ///
+    /// This is the standard mapping Dear ImGui uses for legacy `Io::nav_inputs`, so backends
+    /// can feed the same buttons and axes with [`Io::add_key_analog_event`]. The tweak inputs
+    /// share their key with the focus inputs.
+    pub fn gamepad_key(self) -> Key {
+        match self {
+            NavInput::Activate => Key::GamepadFaceDown,
+            NavInput::Cancel => Key::GamepadFaceRight,
+            NavInput::Input => Key::GamepadFaceUp,
+            NavInput::Menu => Key::GamepadFaceLeft,
+            NavInput::DpadLeft => Key::GamepadDpadLeft,
+            NavInput::DpadRight => Key::GamepadDpadRight,
+            NavInput::DpadUp => Key::GamepadDpadUp,
+            NavInput::DpadDown => Key::GamepadDpadDown,
+            NavInput::LStickLeft => Key::GamepadLStickLeft,
+            NavInput::LStickRight => Key::GamepadLStickRight,
+            NavInput::LStickUp => Key::GamepadLStickUp,
+            NavInput::LStickDown => Key::GamepadLStickDown,
+            NavInput::FocusPrev | NavInput::TweakSlow => Key::GamepadL1,
+            NavInput::FocusNext | NavInput::TweakFast => Key::GamepadR1,
+        }
+    }
 }
 
 #[test]
@@ -315,7 +339,8 @@ pub struct Io {
     /// Mouse wheel (horizontal).
     ///
     /// Most users don't have a mouse with a horizontal wheel, and may not be filled by all
-    /// backends.
+    /// backends. Touchpads usually produce horizontal scrolling too; backends should queue it
+    /// with [`add_mouse_wheel_event`](Self::add_mouse_wheel_event).
     pub mouse_wheel_h: f32,
     #[cfg(feature = "docking")]
     mouse_hovered_viewport: sys::ImGuiID,
@@ -402,6 +427,19 @@ impl Io {
             .map(|c| core::char::from_u32(*c).unwrap_or(core::char::REPLACEMENT_CHARACTER))
     }
 
+    /// Returns the user data pointer, see [`set_user_data`](Self::set_user_data)
+    pub fn user_data(&self) -> *mut c_void {
+        self.user_data
+    }
+
+    /// Sets the user data pointer, which Dear ImGui never touches.
+    ///
+    /// [`Context::set_user_data`](crate::Context::set_user_data) sets this to the value it
+    /// stores. Setting it here doesn't change that value, only the pointer.
+    pub fn set_user_data(&mut self, user_data: *mut c_void) {
+        self.user_data = user_data;
+    }
+
     pub fn update_delta_time(&mut self, delta: Duration) {
         self.delta_time = delta.as_secs_f32().max(f32::MIN_POSITIVE);
     }
@@ -418,6 +456,16 @@ impl Io {
         }
     }
 
+    /// Queues a mouse wheel movement as `[horizontal, vertical]`, in lines.
+    ///
+    /// Positive values scroll up and left. Fractional values from precise touchpads are kept
+    /// as is. Both axes end up in [`mouse_wheel_h`](Self::mouse_wheel_h) and
+    /// [`mouse_wheel`](Self::mouse_wheel) during the next frame, and scroll the hovered
+    /// window; holding Shift turns vertical scrolling into horizontal scrolling.
+    ///
+    /// As for other mouse input, check [`want_capture_mouse`](Self::want_capture_mouse) before
+    /// also using the wheel in the application, e.g. to zoom the scene under the UI.
+    #[doc(alias = "AddMouseWheelEvent")]
     pub fn add_mouse_wheel_event(&mut self, wheel: [f32; 2]) {
         unsafe {
             sys::ImGuiIO_AddMouseWheelEvent(self.raw_mut(), wheel[0], wheel[1]);
@@ -429,6 +477,184 @@ impl Io {
             sys::ImGuiIO_AddKeyEvent(self.raw_mut(), key as u32, down);
         }
     }
+
+    /// Queues a key event with an analog value (0.0 - 1.0), e.g. for gamepad sticks and triggers.
+    ///
+    /// Gamepad keys only drive navigation when [`ConfigFlags::NAV_ENABLE_GAMEPAD`] is set, and
+    /// are ignored unless the backend also sets [`BackendFlags::HAS_GAMEPAD`]. See
+    /// [`NavInput::gamepad_key`] for the standard button and axis mapping.
+    #[doc(alias = "AddKeyAnalogEvent")]
+    pub fn add_key_analog_event(&mut self, key: Key, down: bool, value: f32) {
+        unsafe {
+            sys::ImGuiIO_AddKeyAnalogEvent(self.raw_mut(), key as u32, down, value);
+        }
+    }
+
+    /// Returns `true` if the settings have changed and should be saved with
+    /// [`Context::save_ini_settings`](crate::Context::save_ini_settings).
+    ///
+    /// This is only raised when automatic .ini saving is disabled (the ini filename is `None`).
+    /// Dear ImGui updates the flag between frames, during `new_frame`, once `ini_saving_rate`
+    /// seconds have passed since the last change to the layout. It is never cleared
+    /// automatically, so call [`clear_want_save_ini_settings`] after saving.
+    ///
+    /// [`clear_want_save_ini_settings`]: Self::clear_want_save_ini_settings
+    pub fn want_save_ini_settings(&self) -> bool {
+        self.want_save_ini_settings
+    }
+
+    /// Clears the `want_save_ini_settings` flag, typically right after the settings have been
+    /// saved.
+    pub fn clear_want_save_ini_settings(&mut self) {
+        self.want_save_ini_settings = false;
+    }
+
+    /// Sets the minimum time between two saves of the settings, in seconds (default is 5.0).
+    ///
+    /// With automatic saving enabled this limits how often the .ini file is written. With manual
+    /// saving it limits how often [`want_save_ini_settings`](Self::want_save_ini_settings) is
+    /// raised.
+    pub fn set_ini_saving_rate(&mut self, secs: f32) {
+        self.ini_saving_rate = secs;
+    }
+
+    /// Returns the global scale applied to all fonts
+    pub fn font_global_scale(&self) -> f32 {
+        self.font_global_scale
+    }
+
+    /// Sets the global scale applied to all fonts (default is 1.0).
+    ///
+    /// This is a cheap way to zoom all text, as the glyphs already in the font atlas are simply
+    /// stretched. Scaling up therefore makes text look blurry; for crisp text at a new size, add
+    /// fonts with a larger `size_pixels` to the [`FontAtlas`] and rebuild it instead.
+    pub fn set_font_global_scale(&mut self, scale: f32) {
+        self.font_global_scale = scale;
+    }
+
+    /// Returns the time a key or button has to be held before it starts repeating, in seconds
+    pub fn key_repeat_delay(&self) -> f32 {
+        self.key_repeat_delay
+    }
+
+    /// Sets the time a key or button has to be held before it starts repeating, in seconds
+    /// (default is 0.275).
+    ///
+    /// Applies to keyboard input in text fields as well as buttons in
+    /// [repeat mode](crate::Ui::push_button_repeat).
+    pub fn set_key_repeat_delay(&mut self, secs: f32) {
+        self.key_repeat_delay = secs;
+    }
+
+    /// Returns the interval between repeats of a held key or button, in seconds
+    pub fn key_repeat_rate(&self) -> f32 {
+        self.key_repeat_rate
+    }
+
+    /// Sets the interval between repeats of a held key or button, in seconds (default is 0.05).
+    pub fn set_key_repeat_rate(&mut self, secs: f32) {
+        self.key_repeat_rate = secs;
+    }
+
+    /// Returns true if the text cursor blinks in text fields
+    pub fn config_input_text_cursor_blink(&self) -> bool {
+        self.config_input_text_cursor_blink
+    }
+
+    /// Sets whether the text cursor blinks in text fields (default is true).
+    ///
+    /// Some users find the blinking distracting, so this is worth offering as an option.
+    pub fn set_config_input_text_cursor_blink(&mut self, blink: bool) {
+        self.config_input_text_cursor_blink = blink;
+    }
+
+    /// Returns true if pressing Enter keeps single-line text fields active
+    pub fn config_input_text_enter_keep_active(&self) -> bool {
+        self.config_input_text_enter_keep_active
+    }
+
+    /// Sets whether pressing Enter keeps a single-line text field active and selects its
+    /// contents, instead of deactivating it (default is false).
+    ///
+    /// This is marked as beta in Dear ImGui 1.89.
+    pub fn set_config_input_text_enter_keep_active(&mut self, keep_active: bool) {
+        self.config_input_text_enter_keep_active = keep_active;
+    }
+
+    /// Returns true if clicking a drag widget turns it into a text field
+    pub fn config_drag_click_to_input_text(&self) -> bool {
+        self.config_drag_click_to_input_text
+    }
+
+    /// Sets whether clicking and releasing a drag widget without moving turns it into a text
+    /// field (default is false).
+    ///
+    /// Not desirable on devices without a keyboard. This is marked as beta in Dear ImGui 1.89.
+    pub fn set_config_drag_click_to_input_text(&mut self, click_to_input: bool) {
+        self.config_drag_click_to_input_text = click_to_input;
+    }
+
+    /// Returns true if windows can be resized from their edges
+    pub fn config_windows_resize_from_edges(&self) -> bool {
+        self.config_windows_resize_from_edges
+    }
+
+    /// Sets whether windows can be resized from their edges and lower-left corner, in
+    /// addition to the lower-right grip (default is true).
+    ///
+    /// Only works if the backend sets [`BackendFlags::HAS_MOUSE_CURSORS`], since the user
+    /// needs the cursor shape as feedback.
+    pub fn set_config_windows_resize_from_edges(&mut self, resize: bool) {
+        self.config_windows_resize_from_edges = resize;
+    }
+
+    /// Returns true if windows can only be moved by dragging their title bar
+    pub fn config_windows_move_from_title_bar_only(&self) -> bool {
+        self.config_windows_move_from_title_bar_only
+    }
+
+    /// Sets whether windows can only be moved by dragging their title bar, rather than by
+    /// clicking and dragging anywhere on them (default is false).
+    ///
+    /// Windows without a title bar are not affected.
+    pub fn set_config_windows_move_from_title_bar_only(&mut self, title_bar_only: bool) {
+        self.config_windows_move_from_title_bar_only = title_bar_only;
+    }
+
+    /// Returns true if Dear ImGui draws the mouse cursor itself
+    pub fn mouse_draw_cursor(&self) -> bool {
+        self.mouse_draw_cursor
+    }
+
+    /// Sets the mouse position, in pixels, for backends that don't queue input events.
+    ///
+    /// Queued events take precedence: with [`add_mouse_pos_event`](Self::add_mouse_pos_event)
+    /// the position is only updated when the event is processed in the next frame.
+    pub fn set_mouse_pos(&mut self, pos: [f32; 2]) {
+        self.mouse_pos = pos;
+    }
+
+    /// Returns true if Dear ImGui moved [`mouse_pos`](Self::mouse_pos) itself, and wants the
+    /// backend to warp the OS cursor there.
+    ///
+    /// This only happens with [`ConfigFlags::NAV_ENABLE_SET_MOUSE_POS`], e.g. when keyboard or
+    /// gamepad navigation moves the focus. A backend advertising
+    /// [`BackendFlags::HAS_SET_MOUSE_POS`] must check this after building each frame and move
+    /// the OS cursor accordingly, or the mouse will keep snapping back to its old position.
+    pub fn want_set_mouse_pos(&self) -> bool {
+        self.want_set_mouse_pos
+    }
+
+    /// Sets whether Dear ImGui draws the mouse cursor itself (default is false).
+    ///
+    /// The cursor is drawn into the foreground draw list using the shape from
+    /// [`Ui::mouse_cursor`](crate::Ui::mouse_cursor), which is useful when there is no OS
+    /// cursor, e.g. on consoles or when rendering fullscreen. The platform backend should hide
+    /// the OS cursor in that case. The cursor shapes come from the font atlas, which contains
+    /// them unless it was built with [`FontAtlasFlags::NO_MOUSE_CURSORS`](crate::FontAtlasFlags::NO_MOUSE_CURSORS).
+    pub fn set_mouse_draw_cursor(&mut self, draw: bool) {
+        self.mouse_draw_cursor = draw;
+    }
 }
 
 impl Index<Key> for Io {
@@ -470,6 +696,244 @@ impl IndexMut<MouseButton> for Io {
     }
 }
 
+/// Input queue used by platform backends to feed events to Dear ImGui.
+///
+/// Platform integrations only need to provide [`io_mut`](Self::io_mut); the other methods
+/// forward to the `Io::add_*_event` functions, so backends don't depend on the layout of
+/// [`Io`] or on how Dear ImGui processes input internally.
+///
+/// Per frame, the expected call order is:
+///
+/// 1. queue the events received from the platform since the last frame, in the order they
+///    happened (`queue_*`),
+/// 2. call [`begin_frame`](Self::begin_frame) with the current display size and the time
+///    elapsed since the last frame,
+/// 3. start the frame with [`Context::new_frame`](crate::Context::new_frame).
+///
+/// Events queued after `new_frame` are processed in the next frame. With
+/// [`config_input_trickle_event_queue`](Io::config_input_trickle_event_queue) set (the
+/// default), Dear ImGui may also spread the events of one frame over several frames, e.g. a key
+/// and a mouse button pressed at the same time.
+pub trait Backend {
+    /// Returns the io structure the events are queued on
+    fn io_mut(&mut self) -> &mut Io;
+
+    /// Queues a mouse move, in screen space
+    fn queue_mouse_pos(&mut self, pos: [f32; 2]) {
+        self.io_mut().add_mouse_pos_event(pos);
+    }
+    /// Queues a mouse button press or release
+    fn queue_mouse_button(&mut self, button: MouseButton, down: bool) {
+        self.io_mut().add_mouse_button_event(button, down);
+    }
+    /// Queues a mouse wheel movement, in lines (positive is up / right)
+    fn queue_mouse_wheel(&mut self, wheel: [f32; 2]) {
+        self.io_mut().add_mouse_wheel_event(wheel);
+    }
+    /// Queues a key press or release
+    fn queue_key(&mut self, key: Key, down: bool) {
+        self.io_mut().add_key_event(key, down);
+    }
+    /// Queues a character of text input
+    fn queue_char(&mut self, character: char) {
+        self.io_mut().add_input_character(character);
+    }
+    /// Sets the display size and the time elapsed since the last frame, right before the frame
+    /// is started.
+    fn begin_frame(&mut self, display_size: [f32; 2], delta: Duration) {
+        let io = self.io_mut();
+        io.display_size = display_size;
+        io.update_delta_time(delta);
+    }
+}
+
+impl Backend for Io {
+    fn io_mut(&mut self) -> &mut Io {
+        self
+    }
+}
+
+impl Backend for crate::Context {
+    fn io_mut(&mut self) -> &mut Io {
+        crate::Context::io_mut(self)
+    }
+}
+
+#[test]
+fn test_backend_queue() {
+    // A backend keeping the context next to its own state, as platform crates do
+    struct MockBackend {
+        ctx: crate::Context,
+        queued_chars: usize,
+    }
+    impl Backend for MockBackend {
+        fn io_mut(&mut self) -> &mut Io {
+            self.ctx.io_mut()
+        }
+        fn queue_char(&mut self, character: char) {
+            self.queued_chars += 1;
+            self.io_mut().add_input_character(character);
+        }
+    }
+
+    let (_guard, ctx) = crate::test::test_ctx_initialized();
+    let mut backend = MockBackend {
+        ctx,
+        queued_chars: 0,
+    };
+    backend.queue_mouse_pos([30.0, 40.0]);
+    backend.queue_mouse_button(MouseButton::Left, true);
+    backend.queue_key(Key::A, true);
+    backend.queue_char('a');
+    backend.begin_frame([320.0, 240.0], Duration::from_millis(20));
+    assert_eq!(backend.queued_chars, 1);
+
+    let ui = backend.ctx.new_frame();
+    assert_eq!(ui.io().display_size, [320.0, 240.0]);
+    assert!((ui.io().delta_time - 0.02).abs() < 1e-6);
+    assert_eq!(ui.io().mouse_pos, [30.0, 40.0]);
+    assert!(ui.is_mouse_down(MouseButton::Left));
+    let _ = backend.ctx.render();
+
+    // The key event trickles into the next frame, after the mouse button
+    backend.begin_frame([320.0, 240.0], Duration::from_millis(20));
+    let ui = backend.ctx.new_frame();
+    assert!(ui.is_key_down(Key::A));
+    assert_eq!(ui.io().peek_input_characters(), "a");
+}
+
+#[test]
+fn test_horizontal_mouse_wheel() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.io_mut().config_input_trickle_event_queue = false;
+    let mut scroll_x = 0.0;
+    for frame in 0..3 {
+        ctx.io_mut().add_mouse_pos_event([100.0, 100.0]);
+        if frame == 1 {
+            ctx.io_mut().add_mouse_wheel_event([-0.5, 0.0]);
+        }
+        let ui = ctx.new_frame();
+        if frame == 1 {
+            assert_eq!(ui.io().mouse_wheel_h, -0.5);
+            assert_eq!(ui.io().mouse_wheel, 0.0);
+        }
+        ui.window("Timeline")
+            .position([0.0, 0.0], crate::Condition::Always)
+            .size([200.0, 200.0], crate::Condition::Always)
+            .content_size([1000.0, 100.0])
+            .horizontal_scrollbar(true)
+            .build(|| scroll_x = ui.scroll_x());
+        let _ = ctx.render();
+    }
+    assert!(scroll_x > 0.0);
+}
+
+#[test]
+fn test_want_save_ini_settings() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.io_mut().ini_saving_rate = 0.001;
+    assert!(!ctx.io().want_save_ini_settings());
+    {
+        let ui = ctx.new_frame();
+        ui.window("Test")
+            .position([10.0, 10.0], crate::Condition::Always)
+            .build(|| {});
+        let _ = ctx.render();
+    }
+    {
+        let _ = ctx.new_frame();
+        let _ = ctx.render();
+    }
+    assert!(ctx.io().want_save_ini_settings());
+    ctx.io_mut().clear_want_save_ini_settings();
+    assert!(!ctx.io().want_save_ini_settings());
+}
+
+#[test]
+fn test_key_repeat_settings() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let io = ctx.io_mut();
+    assert_eq!(io.key_repeat_delay(), 0.275);
+    assert_eq!(io.key_repeat_rate(), 0.05);
+    io.set_key_repeat_delay(0.1);
+    io.set_key_repeat_rate(0.02);
+    assert_eq!(io.key_repeat_delay(), 0.1);
+    assert_eq!(io.key_repeat_rate(), 0.02);
+    let raw = unsafe { &*sys::igGetIO() };
+    assert_eq!(raw.KeyRepeatDelay, 0.1);
+    assert_eq!(raw.KeyRepeatRate, 0.02);
+}
+
+#[test]
+fn test_text_edit_config() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let io = ctx.io_mut();
+    assert!(io.config_input_text_cursor_blink());
+    assert!(!io.config_input_text_enter_keep_active());
+    assert!(!io.config_drag_click_to_input_text());
+    io.set_config_input_text_cursor_blink(false);
+    io.set_config_input_text_enter_keep_active(true);
+    io.set_config_drag_click_to_input_text(true);
+    let raw = unsafe { &*sys::igGetIO() };
+    assert!(!raw.ConfigInputTextCursorBlink);
+    assert!(raw.ConfigInputTextEnterKeepActive);
+    assert!(raw.ConfigDragClickToInputText);
+}
+
+#[test]
+fn test_window_move_and_resize_config() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let io = ctx.io_mut();
+    assert!(io.config_windows_resize_from_edges());
+    assert!(!io.config_windows_move_from_title_bar_only());
+    io.set_config_windows_resize_from_edges(false);
+    io.set_config_windows_move_from_title_bar_only(true);
+    assert!(!io.config_windows_resize_from_edges());
+    assert!(io.config_windows_move_from_title_bar_only());
+    let raw = unsafe { &*sys::igGetIO() };
+    assert!(!raw.ConfigWindowsResizeFromEdges);
+    assert!(raw.ConfigWindowsMoveFromTitleBarOnly);
+}
+
+#[test]
+fn test_set_mouse_pos() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.io_mut().set_mouse_pos([12.0, 34.0]);
+    assert_eq!(
+        unsafe { (*sys::igGetIO()).MousePos },
+        sys::ImVec2::new(12.0, 34.0)
+    );
+    let ui = ctx.new_frame();
+    assert_eq!(ui.io().mouse_pos, [12.0, 34.0]);
+    assert!(!ui.io().want_set_mouse_pos());
+}
+
+#[test]
+fn test_mouse_draw_cursor() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let io = ctx.io_mut();
+    assert!(!io.mouse_draw_cursor());
+    io.set_mouse_draw_cursor(true);
+    assert!(io.mouse_draw_cursor());
+    assert!(unsafe { (*sys::igGetIO()).MouseDrawCursor });
+    io.set_mouse_draw_cursor(false);
+    assert!(!unsafe { (*sys::igGetIO()).MouseDrawCursor });
+}
+
+#[test]
+fn test_add_key_analog_event() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let io = ctx.io_mut();
+    io.config_flags |= ConfigFlags::NAV_ENABLE_GAMEPAD;
+    io.backend_flags |= BackendFlags::HAS_GAMEPAD;
+    let key = NavInput::LStickLeft.gamepad_key();
+    io.add_key_analog_event(key, true, 0.25);
+    let _ = ctx.new_frame();
+    let data = &ctx.io().keys_data[key as usize - sys::ImGuiKey_KeysData_OFFSET as usize];
+    assert!(data.Down);
+    assert_eq!(data.AnalogValue, 0.25);
+}
+
 #[test]
 #[cfg(test)]
 fn test_io_memory_layout() {
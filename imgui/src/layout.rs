@@ -13,14 +13,77 @@ create_token!(
 
 /// # Cursor / Layout
 impl Ui {
+    /// Thickness of the lines drawn by [`separator_with_text`](Self::separator_with_text), the
+    /// default `SeparatorTextBorderSize` of Dear ImGui 1.89.4.
+    pub const SEPARATOR_TEXT_THICKNESS: f32 = 3.0;
+
     /// Renders a separator (generally horizontal).
     ///
     /// This becomes a vertical separator inside a menu bar or in horizontal layout mode.
     #[doc(alias = "Separator")]
+    #[track_caller]
     pub fn separator(&self) {
+        self.assert_in_frame("Ui::separator");
         unsafe { sys::igSeparator() }
     }
 
+    /// Renders a horizontal separator with a text label, to title a section.
+    ///
+    /// `SeparatorText` only exists from Dear ImGui 1.89.4 on, and so do its style settings, so
+    /// this draws the same thing using the closest settings of the current style: the label is
+    /// placed `indent_spacing` pixels in and aligned horizontally like window titles
+    /// (`window_title_align`), with `frame_padding` above and below it, and the line on either
+    /// side is [`SEPARATOR_TEXT_THICKNESS`](Self::SEPARATOR_TEXT_THICKNESS) pixels thick, in the
+    /// `Separator` color.
+    ///
+    /// Like other labels, anything from `##` on is only used for the id and isn't rendered.
+    #[doc(alias = "SeparatorText")]
+    #[track_caller]
+    pub fn separator_with_text(&self, label: impl AsRef<str>) {
+        self.assert_in_frame("Ui::separator_with_text");
+        let label = label.as_ref();
+        let label = label.find("##").map_or(label, |end| &label[..end]);
+        let (padding, align, spacing) = {
+            let style = unsafe { self.style() };
+            (
+                [style.indent_spacing, style.frame_padding[1]],
+                style.window_title_align[0],
+                style.item_spacing[0].max(0.0),
+            )
+        };
+        let thickness = Self::SEPARATOR_TEXT_THICKNESS;
+        let pos = self.cursor_screen_pos();
+        let width = self.content_region_avail()[0];
+        let text_size = self.calc_text_size(label);
+        let height = text_size[1] + padding[1] * 2.0;
+        let line_y = (pos[1] + height / 2.0).floor();
+        let label_avail_w = (width - padding[0] * 2.0).max(0.0);
+        let text_x = pos[0] + padding[0] + (label_avail_w - text_size[0]).max(0.0) * align;
+        let right_x = pos[0] + width;
+        // Drawn without `get_window_draw_list`, which panics if the caller holds it already
+        unsafe {
+            let draw_list = sys::igGetWindowDrawList();
+            let color = sys::igGetColorU32_Col(sys::ImGuiCol_Separator as i32, 1.0);
+            if text_x - spacing > pos[0] {
+                let (p1, p2) = ([pos[0], line_y], [text_x - spacing, line_y]);
+                sys::ImDrawList_AddLine(draw_list, p1.into(), p2.into(), color, thickness);
+            }
+            if text_x + text_size[0] + spacing < right_x {
+                let (p1, p2) = ([text_x + text_size[0] + spacing, line_y], [right_x, line_y]);
+                sys::ImDrawList_AddLine(draw_list, p1.into(), p2.into(), color, thickness);
+            }
+            let text_pos = [text_x, pos[1] + padding[1]];
+            sys::ImDrawList_AddText_Vec2(
+                draw_list,
+                text_pos.into(),
+                sys::igGetColorU32_Col(sys::ImGuiCol_Text as i32, 1.0),
+                label.as_ptr() as *const _,
+                label.as_ptr().add(label.len()) as *const _,
+            );
+        }
+        self.dummy([width, height]);
+    }
+
     /// Call between widgets or groups to layout them horizontally.
     ///
     /// X position is given in window coordinates.
@@ -28,7 +91,9 @@ impl Ui {
     /// This is equivalent to calling [same_line_with_pos](Self::same_line_with_pos)
     /// with the `pos` set to 0.0, which uses `Style::item_spacing`.
     #[doc(alias = "SameLine")]
+    #[track_caller]
     pub fn same_line(&self) {
+        self.assert_in_frame("Ui::same_line");
         self.same_line_with_pos(0.0);
     }
 
@@ -39,7 +104,9 @@ impl Ui {
     /// This is equivalent to calling [same_line_with_spacing](Self::same_line_with_spacing)
     /// with the `spacing` set to -1.0, which means no extra spacing.
     #[doc(alias = "SameLine")]
+    #[track_caller]
     pub fn same_line_with_pos(&self, pos_x: f32) {
+        self.assert_in_frame("Ui::same_line_with_pos");
         self.same_line_with_spacing(pos_x, -1.0)
     }
 
@@ -47,25 +114,33 @@ impl Ui {
     ///
     /// X position is given in window coordinates.
     #[doc(alias = "SameLine")]
+    #[track_caller]
     pub fn same_line_with_spacing(&self, pos_x: f32, spacing_w: f32) {
+        self.assert_in_frame("Ui::same_line_with_spacing");
         unsafe { sys::igSameLine(pos_x, spacing_w) }
     }
 
     /// Undo a `same_line` call or force a new line when in horizontal layout mode
     #[doc(alias = "NewLine")]
+    #[track_caller]
     pub fn new_line(&self) {
+        self.assert_in_frame("Ui::new_line");
         unsafe { sys::igNewLine() }
     }
     /// Adds vertical spacing
     #[doc(alias = "Spacing")]
+    #[track_caller]
     pub fn spacing(&self) {
+        self.assert_in_frame("Ui::spacing");
         unsafe { sys::igSpacing() }
     }
     /// Fills a space of `size` in pixels with nothing on the current window.
     ///
     /// Can be used to move the cursor on the window.
     #[doc(alias = "Dummy")]
+    #[track_caller]
     pub fn dummy(&self, size: impl Into<MintVec2>) {
+        self.assert_in_frame("Ui::dummy");
         unsafe { sys::igDummy(size.into().into()) }
     }
 
@@ -74,13 +149,17 @@ impl Ui {
     /// This is equivalent to [indent_by](Self::indent_by) with `width` set to
     /// `Style::ident_spacing`.
     #[doc(alias = "Indent")]
+    #[track_caller]
     pub fn indent(&self) {
+        self.assert_in_frame("Ui::indent");
         self.indent_by(0.0)
     }
 
     /// Moves content position to the right by `width`
     #[doc(alias = "Indent")]
+    #[track_caller]
     pub fn indent_by(&self, width: f32) {
+        self.assert_in_frame("Ui::indent_by");
         unsafe { sys::igIndent(width) };
     }
     /// Moves content position to the left by `Style::indent_spacing`
@@ -88,12 +167,16 @@ impl Ui {
     /// This is equivalent to [unindent_by](Self::unindent_by) with `width` set to
     /// `Style::ident_spacing`.
     #[doc(alias = "Unindent")]
+    #[track_caller]
     pub fn unindent(&self) {
+        self.assert_in_frame("Ui::unindent");
         self.unindent_by(0.0)
     }
     /// Moves content position to the left by `width`
     #[doc(alias = "Unindent")]
+    #[track_caller]
     pub fn unindent_by(&self, width: f32) {
+        self.assert_in_frame("Ui::unindent_by");
         unsafe { sys::igUnindent(width) };
     }
     /// Groups items together as a single item.
@@ -102,7 +185,9 @@ impl Ui {
     ///
     /// Returns a `GroupToken` that must be ended by calling `.end()`
     #[doc(alias = "BeginGroup")]
+    #[track_caller]
     pub fn begin_group(&self) -> GroupToken<'_> {
+        self.assert_in_frame("Ui::begin_group");
         unsafe { sys::igBeginGroup() };
         GroupToken::new(self)
     }
@@ -110,7 +195,9 @@ impl Ui {
     ///
     /// May be useful to handle the same mouse event on a group of items, for example.
     #[doc(alias = "BeginGroup")]
+    #[track_caller]
     pub fn group<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        self.assert_in_frame("Ui::group");
         let group = self.begin_group();
         let result = f();
         group.end();
@@ -118,7 +205,9 @@ impl Ui {
     }
     /// Returns the cursor position (in window coordinates)
     #[doc(alias = "GetCursorPos")]
+    #[track_caller]
     pub fn cursor_pos(&self) -> [f32; 2] {
+        self.assert_in_frame("Ui::cursor_pos");
         let mut out = sys::ImVec2::zero();
         unsafe { sys::igGetCursorPos(&mut out) };
         out.into()
@@ -127,12 +216,16 @@ impl Ui {
     ///
     /// This sets the point on which the next widget will be drawn.
     #[doc(alias = "SetCursorPos")]
+    #[track_caller]
     pub fn set_cursor_pos(&self, pos: impl Into<MintVec2>) {
+        self.assert_in_frame("Ui::set_cursor_pos");
         unsafe { sys::igSetCursorPos(pos.into().into()) };
     }
     /// Returns the initial cursor position (in window coordinates)
     #[doc(alias = "GetCursorStartPos")]
+    #[track_caller]
     pub fn cursor_start_pos(&self) -> [f32; 2] {
+        self.assert_in_frame("Ui::cursor_start_pos");
         let mut out = sys::ImVec2::zero();
         unsafe { sys::igGetCursorStartPos(&mut out) };
         out.into()
@@ -141,21 +234,27 @@ impl Ui {
     ///
     /// This is especially useful for drawing, as the drawing API uses screen coordinates.
     #[doc(alias = "GetCursorScreenPos")]
+    #[track_caller]
     pub fn cursor_screen_pos(&self) -> [f32; 2] {
+        self.assert_in_frame("Ui::cursor_screen_pos");
         let mut out = sys::ImVec2::zero();
         unsafe { sys::igGetCursorScreenPos(&mut out) };
         out.into()
     }
     /// Sets the cursor position (in absolute screen coordinates)
     #[doc(alias = "SetCursorScreenPos")]
+    #[track_caller]
     pub fn set_cursor_screen_pos(&self, pos: impl Into<MintVec2>) {
+        self.assert_in_frame("Ui::set_cursor_screen_pos");
         unsafe { sys::igSetCursorScreenPos(pos.into().into()) }
     }
     /// Vertically aligns text baseline so that it will align properly to regularly frame items.
     ///
     /// Call this if you have text on a line before a framed item.
     #[doc(alias = "AlignTextToFramePadding")]
+    #[track_caller]
     pub fn align_text_to_frame_padding(&self) {
+        self.assert_in_frame("Ui::align_text_to_frame_padding");
         unsafe { sys::igAlignTextToFramePadding() };
     }
     #[doc(alias = "GetTextLineHeight")]
@@ -175,3 +274,103 @@ impl Ui {
         unsafe { sys::igGetFrameHeightWithSpacing() }
     }
 }
+
+#[test]
+fn test_separator_with_text_emits_item() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut vertices = Vec::new();
+    for label in [None, Some("Section")] {
+        let ui = ctx.new_frame();
+        ui.window("Separators")
+            .size([300.0, 100.0], crate::Condition::Always)
+            .build(|| {
+                ui.text("Above");
+                let before = ui.cursor_pos();
+                if let Some(label) = label {
+                    ui.separator_with_text(label);
+                    let size = ui.item_rect_size();
+                    assert!(size[1] > ui.text_line_height());
+                    assert_eq!(size[0], ui.content_region_avail()[0]);
+                    assert!(ui.cursor_pos()[1] > before[1]);
+                }
+            });
+        vertices.push(ctx.render().total_vtx_count);
+    }
+    assert!(vertices[1] > vertices[0]);
+}
+
+#[test]
+fn test_separator_with_text_while_holding_window_draw_list() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    ui.window("Panel").build(|| {
+        let draw_list = ui.get_window_draw_list();
+        ui.separator_with_text("Section");
+        draw_list
+            .add_line([0.0, 0.0], [10.0, 10.0], [1.0, 1.0, 1.0, 1.0])
+            .build();
+    });
+    let _ = ctx.render();
+}
+
+#[test]
+fn test_separator_with_text_follows_style() {
+    // Returns where the line left of the label ends, relative to the item
+    fn left_line_end(align: f32, indent: f32) -> f32 {
+        const SEPARATOR_PACKED: [u8; 4] = [255, 0, 255, 255];
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        let style = ctx.style_mut();
+        style.window_title_align = [align, 0.5];
+        style.indent_spacing = indent;
+        // The lines don't depend on the frame padding
+        style.frame_padding[1] = 0.0;
+        style[crate::StyleColor::Separator] = [1.0, 0.0, 1.0, 1.0];
+        let mut item_left = 0.0;
+        let mut xs = Vec::new();
+        for _ in 0..2 {
+            let ui = ctx.new_frame();
+            ui.window("Panel")
+                .size([300.0, 100.0], crate::Condition::Always)
+                .build(|| {
+                    ui.separator_with_text("Section");
+                    item_left = ui.item_rect_min()[0];
+                });
+            xs = ctx
+                .render()
+                .draw_lists()
+                .flat_map(|list| list.vtx_buffer())
+                .filter(|vtx| vtx.col == SEPARATOR_PACKED)
+                .map(|vtx| vtx.pos[0])
+                .collect();
+        }
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        // Group the (anti-aliased) line vertices by line end
+        let mut ends: Vec<f32> = Vec::new();
+        for x in xs {
+            match ends.last() {
+                Some(&end) if x - end < 2.0 => {}
+                _ => ends.push(x),
+            }
+        }
+        assert_eq!(ends.len(), 4, "expected two lines, got ends {:?}", ends);
+        ends[1] - item_left
+    }
+    let left = left_line_end(0.0, 20.0);
+    assert!(left > 0.0 && left < 20.0);
+    assert!(left_line_end(0.0, 40.0) > left + 15.0);
+    assert!(left_line_end(1.0, 20.0) > left + 100.0);
+}
+
+#[test]
+fn test_separator_with_text_hides_id_suffix() {
+    fn vtx_count(label: &str) -> usize {
+        crate::test::headless(|ui| {
+            ui.window("Sections")
+                .size([300.0, 100.0], crate::Condition::Always)
+                .build(|| ui.separator_with_text(label));
+        })
+        .vtx_count
+    }
+    assert_eq!(vtx_count("Section##id"), vtx_count("Section"));
+    assert!(vtx_count("Section") > vtx_count("##id"));
+}
@@ -1,3 +1,11 @@
+//! Safe Rust bindings for [Dear ImGui](https://github.com/ocornut/imgui).
+//!
+//! # Unstable APIs
+//!
+//! Items marked **Unstable** use Dear ImGui's internal API (`imgui_internal.h`). They are only
+//! available with the `docking` feature, as only the bindings generated for the docking branch
+//! include the internal API, and they may change or disappear with any Dear ImGui update.
+
 #![cfg_attr(test, allow(clippy::float_cmp))]
 #![deny(rust_2018_idioms)]
 //#![deny(missing_docs)]
@@ -16,15 +24,18 @@ pub use self::fonts::atlas::*;
 pub use self::fonts::font::*;
 pub use self::fonts::glyph::*;
 pub use self::fonts::glyph_ranges::*;
+pub use self::headless::*;
 pub use self::input::keyboard::*;
 pub use self::input::mouse::*;
 pub use self::input_widget::*;
 pub use self::io::*;
 pub use self::layout::*;
 pub use self::list_clipper::ListClipper;
+pub use self::logging::*;
 pub use self::plothistogram::PlotHistogram;
 pub use self::plotlines::PlotLines;
 pub use self::popups::*;
+pub use self::profiling::*;
 pub use self::render::draw_data::*;
 pub use self::render::renderer::*;
 pub use self::stacks::*;
@@ -33,6 +44,7 @@ pub use self::style::*;
 
 #[cfg(feature = "tables-api")]
 pub use self::tables::*;
+pub use self::text_buffer::TextBuffer;
 pub use self::text_filter::*;
 pub use self::utils::*;
 pub use self::widget::color_editors::*;
@@ -65,16 +77,19 @@ mod context;
 pub mod drag_drop;
 pub mod draw_list;
 mod fonts;
+mod headless;
 mod input;
 mod input_widget;
 pub mod internal;
 mod io;
 mod layout;
 mod list_clipper;
+mod logging;
 mod math;
 mod plothistogram;
 mod plotlines;
 mod popups;
+mod profiling;
 mod render;
 mod stacks;
 mod style;
@@ -82,6 +97,7 @@ mod style;
 mod tables;
 #[cfg(test)]
 mod test;
+mod text_buffer;
 pub mod text_filter;
 mod utils;
 mod widget;
@@ -92,7 +108,16 @@ mod window;
 #[doc(hidden)]
 pub use core as __core;
 
+/// Version of the Dear ImGui library imgui-rs was built against, e.g. `"1.89.2"`
+///
+/// This is read from the bundled headers at build time. Use [`dear_imgui_version`] to get
+/// the version of the library that is actually linked.
+pub const DEAR_IMGUI_VERSION: &str = sys::DEAR_IMGUI_VERSION;
+
 /// Returns the underlying Dear ImGui library version
+///
+/// This is the version of the linked library, which normally equals [`DEAR_IMGUI_VERSION`].
+/// Include it in bug reports.
 #[doc(alias = "GetVersion")]
 pub fn dear_imgui_version() -> &'static str {
     unsafe {
@@ -101,6 +126,14 @@ pub fn dear_imgui_version() -> &'static str {
     }
 }
 
+#[test]
+fn test_dear_imgui_version() {
+    let version = dear_imgui_version();
+    assert!(!version.is_empty());
+    assert_eq!(version, DEAR_IMGUI_VERSION);
+    assert!(version.starts_with("1."));
+}
+
 impl Context {
     /// Returns the global imgui-rs time.
     ///
@@ -123,6 +156,15 @@ impl Context {
 pub struct Ui {
     /// our scratch sheet
     buffer: cell::UnsafeCell<string::UiBuffer>,
+    /// strings handed out by `scratch_cstr`, freed at the start of each frame
+    frame_arena: cell::UnsafeCell<string::FrameArena>,
+    /// set with `Context::set_user_data`
+    user_data: Option<Box<dyn std::any::Any>>,
+    /// true while a `LogToken` is alive, see `Ui::log_to_clipboard`
+    log_active: cell::Cell<bool>,
+    /// false once the frame has ended, see `assert_in_frame`
+    #[cfg(debug_assertions)]
+    in_frame: bool,
 }
 
 impl Ui {
@@ -143,6 +185,38 @@ impl Ui {
         &self.buffer
     }
 
+    /// Copies `txt` into a per-frame arena and returns it as an [`ImStr`], valid until the
+    /// end of the frame.
+    ///
+    /// This is meant for the few APIs that still need a `&ImStr`: no heap allocation happens
+    /// once the arena has grown to the frame's needs, since it is reset by
+    /// [`Context::new_frame`] and keeps its memory. Like Dear ImGui itself would, the string
+    /// is cut at the first nul byte, if any.
+    pub fn scratch_cstr(&self, txt: impl AsRef<str>) -> &ImStr {
+        unsafe {
+            let arena = &mut *self.frame_arena.get();
+            ImStr::from_ptr_unchecked(arena.push(txt.as_ref()) as *const c_char)
+        }
+    }
+
+    /// Panics if the frame of this `Ui` has already ended, e.g. after
+    /// [`end_frame_early`](Self::end_frame_early), instead of letting Dear ImGui assert.
+    ///
+    /// Only checked in debug builds.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn assert_in_frame(&self, method: &str) {
+        #[cfg(debug_assertions)]
+        assert!(
+            self.in_frame,
+            "{} called after the end of the frame: widgets can only be submitted between \
+             `Context::new_frame` and `Context::render` or `Ui::end_frame_early`",
+            method
+        );
+        #[cfg(not(debug_assertions))]
+        let _ = method;
+    }
+
     /// Internal method to push a single text to our scratch buffer.
     fn scratch_txt(&self, txt: impl AsRef<str>) -> *const sys::cty::c_char {
         unsafe {
@@ -187,6 +261,15 @@ impl Ui {
         unsafe { &*(sys::igGetIO() as *const Io) }
     }
 
+    /// Returns the value set with [`Context::set_user_data`], or `None` if there is none or it
+    /// isn't a `T`.
+    ///
+    /// This makes application state reachable from code that only has access to the `Ui`, such
+    /// as input text callback handlers holding a `&Ui`.
+    pub fn user_data<T: std::any::Any>(&self) -> Option<&T> {
+        self.user_data.as_ref()?.downcast_ref()
+    }
+
     /// Returns an immutable reference to the font atlas.
     pub fn fonts(&self) -> &FontAtlas {
         unsafe { &*(self.io().fonts as *const FontAtlas) }
@@ -219,6 +302,10 @@ impl Ui {
     ///
     /// You probably *don't want this function.* If you want to render your data, use `Context::render` now.
     pub fn end_frame_early(&mut self) {
+        #[cfg(debug_assertions)]
+        {
+            self.in_frame = false;
+        }
         unsafe {
             sys::igEndFrame();
         }
@@ -230,7 +317,9 @@ impl Ui {
     /// Renders a demo window (previously called a test window), which demonstrates most
     /// Dear Imgui features.
     #[doc(alias = "ShowDemoWindow")]
+    #[track_caller]
     pub fn show_demo_window(&self, opened: &mut bool) {
+        self.assert_in_frame("Ui::show_demo_window");
         unsafe {
             sys::igShowDemoWindow(opened);
         }
@@ -239,7 +328,9 @@ impl Ui {
     ///
     /// Displays the Dear ImGui version/credits, and build/system information.
     #[doc(alias = "ShowAboutWindow")]
+    #[track_caller]
     pub fn show_about_window(&self, opened: &mut bool) {
+        self.assert_in_frame("Ui::show_about_window");
         unsafe {
             sys::igShowAboutWindow(opened);
         }
@@ -249,28 +340,69 @@ impl Ui {
     /// Displays Dear ImGui internals: draw commands (with individual draw calls and vertices),
     /// window list, basic internal state, etc.
     #[doc(alias = "ShowMetricsWindow")]
+    #[track_caller]
     pub fn show_metrics_window(&self, opened: &mut bool) {
+        self.assert_in_frame("Ui::show_metrics_window");
         unsafe {
             sys::igShowMetricsWindow(opened);
         }
     }
     /// Renders a style editor block (not a window) for the given `Style` structure
     #[doc(alias = "ShowStyleEditor")]
+    #[track_caller]
     pub fn show_style_editor(&self, style: &mut Style) {
+        self.assert_in_frame("Ui::show_style_editor");
         unsafe {
             sys::igShowStyleEditor(style.raw_mut());
         }
     }
     /// Renders a style editor block (not a window) for the currently active style
     #[doc(alias = "ShowStyleEditor")]
+    #[track_caller]
     pub fn show_default_style_editor(&self) {
+        self.assert_in_frame("Ui::show_default_style_editor");
         unsafe { sys::igShowStyleEditor(std::ptr::null_mut()) };
     }
     /// Renders a basic help/info block (not a window)
     #[doc(alias = "ShowUserGuide")]
+    #[track_caller]
     pub fn show_user_guide(&self) {
+        self.assert_in_frame("Ui::show_user_guide");
         unsafe { sys::igShowUserGuide() };
     }
+    /// Renders a table of the characters in `text`, with the bytes each one is encoded as, its
+    /// codepoint and the glyph the current font renders for it.
+    ///
+    /// Useful to find out why some text renders as the fallback glyph: codepoints that are
+    /// missing from the font or its glyph ranges show up directly.
+    #[doc(alias = "DebugTextEncoding")]
+    #[track_caller]
+    pub fn debug_text_encoding(&self, text: impl AsRef<str>) {
+        self.assert_in_frame("Ui::debug_text_encoding");
+        unsafe { sys::igDebugTextEncoding(self.scratch_txt(text)) };
+    }
+    /// Starts the item picker, a debugging aid for finding out which code submits a widget.
+    ///
+    /// While the picker is active, the widget under the mouse is highlighted and a tooltip shows
+    /// its id. Clicking it ends the picker and breaks into the attached debugger the next time
+    /// that widget is submitted, with the submitting code on the call stack. Pressing Escape
+    /// cancels the picker. Without an attached debugger the break usually terminates the
+    /// process, so only use this in debug builds.
+    ///
+    /// **Unstable**, see [Unstable APIs](crate#unstable-apis).
+    #[cfg(feature = "docking")]
+    #[doc(alias = "DebugStartItemPicker")]
+    #[track_caller]
+    pub fn debug_start_item_picker(&self) {
+        self.assert_in_frame("Ui::debug_start_item_picker");
+        unsafe { sys::igDebugStartItemPicker() };
+    }
+    /// Returns true if the [item picker](Self::debug_start_item_picker) is waiting for a widget
+    /// to be picked.
+    #[cfg(feature = "docking")]
+    pub fn is_debug_item_picker_active(&self) -> bool {
+        unsafe { (*sys::igGetCurrentContext()).DebugItemPickerActive }
+    }
 }
 
 /// Unique ID used by widgets.
@@ -341,6 +473,72 @@ impl Ui {
         };
         Id(value)
     }
+
+    /// Runs `f` the first time this is called with the given `id`, and never again.
+    ///
+    /// A flag is recorded in the state storage of the current window, under `id` hashed with
+    /// the current ID stack. Unlike [`Condition::Once`], which applies per runtime session to
+    /// a single `set_next_*` call, this can wrap any code (e.g. several layout nudges).
+    /// The flag lives as long as the context: it isn't saved in the .ini file, so a new
+    /// context runs `f` again.
+    #[doc(alias = "GetStateStorage")]
+    pub fn run_once(&self, id: impl AsRef<str>, f: impl FnOnce()) {
+        let key = self.new_id_str(id).0;
+        let done = unsafe {
+            let storage = sys::igGetStateStorage();
+            let done = sys::ImGuiStorage_GetBool(storage, key, false);
+            sys::ImGuiStorage_SetBool(storage, key, true);
+            done
+        };
+        if !done {
+            f();
+        }
+    }
+
+    /// Eases a value stored under `id` toward `1.0` if `target` is true, or `0.0` otherwise,
+    /// and returns it. Useful for driving hover highlights or expand/collapse animations.
+    ///
+    /// Call this every frame. The value moves a fraction of its remaining distance each frame,
+    /// based on [`Io::delta_time`]: `speed` is the rate per second, so with a speed of 10.0 it
+    /// covers about 63% of the distance in 0.1 seconds. It snaps to the target once it is close.
+    /// The first call for an `id` returns the target right away, so nothing animates when the
+    /// widget first appears.
+    ///
+    /// Like [`run_once`](Self::run_once), the value is kept in the state storage of the current
+    /// window, under `id` hashed with the current ID stack.
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// ui.button("Hover me");
+    /// let highlight = ui.animate_bool("hover", ui.is_item_hovered(), 12.0);
+    /// let color = ImColor32::from_rgba_f32s(1.0, 1.0, 1.0, 0.25 * highlight);
+    /// # let _ = color;
+    /// ```
+    #[doc(alias = "GetStateStorage")]
+    pub fn animate_bool(&self, id: impl AsRef<str>, target: bool, speed: f32) -> f32 {
+        const SNAP: f32 = 1e-3;
+        let key = self.new_id_str(id).0;
+        let target = if target { 1.0 } else { 0.0 };
+        unsafe {
+            let storage = sys::igGetStateStorage();
+            let current = sys::ImGuiStorage_GetFloat(storage, key, f32::NAN);
+            let value = if current.is_nan() {
+                target
+            } else {
+                let t = 1.0 - (-speed.max(0.0) * self.io().delta_time).exp();
+                let value = current + (target - current) * t;
+                if (target - value).abs() < SNAP {
+                    target
+                } else {
+                    value
+                }
+            };
+            sys::ImGuiStorage_SetFloat(storage, key, value);
+            value
+        }
+    }
 }
 
 impl Ui {
@@ -398,10 +596,14 @@ impl Ui {
         ChildWindow::new(self, name)
     }
 
-    /// Begins constructing a child window with the given name.
+    /// Begins constructing a child window with the given id.
     ///
-    /// Use child windows to begin into a self-contained independent scrolling/clipping
-    /// regions within a host window. Child windows can embed their own child.
+    /// This is useful to give child windows created in a loop a stable identity based on your
+    /// own data, e.g. `ui.child_window_id(ui.new_id_int(item.id))`, without formatting a name.
+    /// The state of a child window, like its scroll position, is stored under its id (combined
+    /// with the parent window), so it follows the item when items are reordered, and two
+    /// children with the same id in the same window share their state.
+    #[doc(alias = "BeginChild")]
     pub fn child_window_id(&self, id: Id) -> ChildWindow<'_> {
         ChildWindow::new_id(self, id)
     }
@@ -592,16 +794,26 @@ impl Ui {
     /// }
     /// ```
     #[doc(alias = "BeginTooltip", alias = "EndTootip")]
+    #[track_caller]
     pub fn tooltip<F: FnOnce()>(&self, f: F) {
+        self.assert_in_frame("Ui::tooltip");
         unsafe { sys::igBeginTooltip() };
         f();
         unsafe { sys::igEndTooltip() };
     }
     /// Construct a tooltip window that can have any kind of content.
     ///
-    /// Returns a `TooltipToken` that must be ended by calling `.end()`
+    /// Returns a `TooltipToken` that must be ended by calling `.end()` (or by dropping it).
+    /// Unlike [`Self::tooltip`], this allows the contents to span multiple statements and use
+    /// early returns.
+    ///
+    /// Tooltips may be opened while a window or popup is being built, but the token must be
+    /// ended before that window or popup is ended, so don't keep it alive across an
+    /// `end()` of the surrounding scope.
     #[doc(alias = "BeginTooltip")]
+    #[track_caller]
     pub fn begin_tooltip(&self) -> TooltipToken<'_> {
+        self.assert_in_frame("Ui::begin_tooltip");
         unsafe { sys::igBeginTooltip() };
         TooltipToken::new(self)
     }
@@ -620,9 +832,68 @@ impl Ui {
     /// }
     /// ```
     #[doc(alias = "BeginTooltip", alias = "EndTootip")]
+    #[track_caller]
     pub fn tooltip_text<T: AsRef<str>>(&self, text: T) {
+        self.assert_in_frame("Ui::tooltip_text");
         self.tooltip(|| self.text(text));
     }
+    /// Begins a tooltip if the last item is hovered.
+    ///
+    /// The item has to be hovered for [`Io::hover_delay_short`] seconds first, like with
+    /// [`ItemHoveredFlags::DELAY_SHORT`]. Returns `None` if no tooltip should be shown.
+    #[doc(alias = "BeginItemTooltip")]
+    #[track_caller]
+    pub fn begin_item_tooltip(&self) -> Option<TooltipToken<'_>> {
+        self.assert_in_frame("Ui::begin_item_tooltip");
+        if self.is_item_hovered_with_flags(ItemHoveredFlags::DELAY_SHORT) {
+            Some(self.begin_tooltip())
+        } else {
+            None
+        }
+    }
+    /// Shows a tooltip with simple text content if the last item is hovered.
+    ///
+    /// This is a shorthand for `if ui.is_item_hovered() { ui.tooltip_text(text) }`, with the
+    /// hover delay of [`begin_item_tooltip`](Self::begin_item_tooltip).
+    #[doc(alias = "SetItemTooltip")]
+    #[track_caller]
+    pub fn set_item_tooltip(&self, text: impl AsRef<str>) {
+        self.assert_in_frame("Ui::set_item_tooltip");
+        if let Some(_tooltip) = self.begin_item_tooltip() {
+            self.text(text);
+        }
+    }
+}
+
+#[test]
+fn test_begin_tooltip_renders_text() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut vertices = Vec::new();
+    for show_tooltip in [false, true, true] {
+        let ui = ctx.new_frame();
+        if show_tooltip {
+            let tooltip = ui.begin_tooltip();
+            ui.text("Tooltip text");
+            tooltip.end();
+        }
+        vertices.push(ctx.render().total_vtx_count);
+    }
+    // The tooltip is hidden during its first frame while it's sized to its contents
+    assert!(vertices[2] > vertices[0]);
+}
+
+#[test]
+fn test_item_tooltip_only_when_hovered() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.io_mut().hover_delay_short = 0.0;
+    let mut hovered = [false, true, true].iter();
+    crate::test::click_item(&mut ctx, &[false; 3], |ui| {
+        ui.button("Hover me");
+        let rect = crate::test::item_rect(ui);
+        ui.set_item_tooltip("Tooltip text");
+        assert_eq!(ui.begin_item_tooltip().is_some(), *hovered.next().unwrap());
+        rect
+    });
 }
 
 create_token!(
@@ -657,7 +928,9 @@ impl Ui {
     /// ```
 
     #[doc(alias = "BeginDisabled")]
+    #[track_caller]
     pub fn begin_disabled(&self, disabled: bool) -> DisabledToken<'_> {
+        self.assert_in_frame("Ui::begin_disabled");
         unsafe { sys::igBeginDisabled(disabled) };
         DisabledToken::new(self)
     }
@@ -666,7 +939,9 @@ impl Ui {
     /// double-negative, for example `begin_enabled(enable_buttons)`
     /// instead of `begin_disabled(!enable_buttons)`)
     #[doc(alias = "BeginDisabled")]
+    #[track_caller]
     pub fn begin_enabled(&self, enabled: bool) -> DisabledToken<'_> {
+        self.assert_in_frame("Ui::begin_enabled");
         self.begin_disabled(!enabled)
     }
 
@@ -684,7 +959,9 @@ impl Ui {
     /// }
     /// ```
     #[doc(alias = "BeginDisabled", alias = "EndDisabled")]
+    #[track_caller]
     pub fn disabled<F: FnOnce()>(&self, disabled: bool, f: F) {
+        self.assert_in_frame("Ui::disabled");
         unsafe { sys::igBeginDisabled(disabled) };
         f();
         unsafe { sys::igEndDisabled() };
@@ -693,7 +970,9 @@ impl Ui {
     /// Same as [`Ui::disabled`] but with logic reversed. See
     /// [`Ui::begin_enabled`].
     #[doc(alias = "BeginDisabled", alias = "EndDisabled")]
+    #[track_caller]
     pub fn enabled<F: FnOnce()>(&self, enabled: bool, f: F) {
+        self.assert_in_frame("Ui::enabled");
         self.disabled(!enabled, f)
     }
 }
@@ -701,6 +980,7 @@ impl Ui {
 // Widgets: ListBox
 impl Ui {
     #[doc(alias = "ListBox")]
+    #[track_caller]
     pub fn list_box<'p, StringType: AsRef<str> + ?Sized>(
         &self,
         label: impl AsRef<str>,
@@ -708,10 +988,19 @@ impl Ui {
         items: &'p [&'p StringType],
         height_in_items: i32,
     ) -> bool {
+        self.assert_in_frame("Ui::list_box");
         let (label_ptr, items_inner) = unsafe {
             let handle = &mut *self.scratch_buffer().get();
 
             handle.refresh_buffer();
+            let label = label.as_ref();
+            // Reserve up front, so pushing the strings can't reallocate (and invalidate the
+            // pointers to) earlier ones
+            let len = items
+                .iter()
+                .map(|item| item.as_ref().len() + 1)
+                .sum::<usize>();
+            handle.buffer.reserve(label.len() + 1 + len);
             let label_ptr = handle.push(label);
 
             let items_inner: Vec<_> = items.iter().map(|&v| handle.push(v)).collect();
@@ -865,7 +1154,9 @@ impl Ui {
     /// ```
     #[must_use]
     #[doc(alias = "GetWindowDrawList")]
+    #[track_caller]
     pub fn get_window_draw_list(&self) -> DrawListMut<'_> {
+        self.assert_in_frame("Ui::get_window_draw_list");
         DrawListMut::window(self)
     }
 
@@ -877,7 +1168,9 @@ impl Ui {
     /// See [`Self::get_window_draw_list`] for more details
     #[must_use]
     #[doc(alias = "GetBackgroundDrawList")]
+    #[track_caller]
     pub fn get_background_draw_list(&self) -> DrawListMut<'_> {
+        self.assert_in_frame("Ui::get_background_draw_list");
         DrawListMut::background(self)
     }
 
@@ -889,7 +1182,9 @@ impl Ui {
     /// See [`Self::get_window_draw_list`] for more details
     #[must_use]
     #[doc(alias = "GetForegroundDrawList")]
+    #[track_caller]
     pub fn get_foreground_draw_list(&self) -> DrawListMut<'_> {
+        self.assert_in_frame("Ui::get_foreground_draw_list");
         DrawListMut::foreground(self)
     }
 }
@@ -927,3 +1222,175 @@ pub enum Direction {
     Up = sys::ImGuiDir_Up,
     Down = sys::ImGuiDir_Down,
 }
+
+#[test]
+fn test_scratch_cstr_arena_is_reset_each_frame() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    {
+        let ui = ctx.new_frame();
+        let first = ui.scratch_cstr("first");
+        let long = "x".repeat(5000);
+        let second = ui.scratch_cstr(&long);
+        // Growing the arena doesn't move earlier strings
+        assert_eq!(first.to_str(), "first");
+        assert_eq!(second.to_str(), long);
+        assert_eq!(ui.scratch_cstr("cut\0off").to_str(), "cut");
+        ui.button(ui.scratch_cstr("Button"));
+        assert_eq!(unsafe { (*ui.frame_arena.get()).len() }, 6 + 5001 + 4 + 7);
+        let _ = ctx.render();
+    }
+    let ui = ctx.new_frame();
+    assert!(unsafe { (*ui.frame_arena.get()).is_empty() });
+    assert_eq!(ui.scratch_cstr("again").to_str(), "again");
+}
+
+#[test]
+fn test_debug_text_encoding() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    for _ in 0..2 {
+        let ui = ctx.new_frame();
+        ui.window("Encoding").build(|| {
+            ui.debug_text_encoding("a\u{e9}\u{4e2d}\u{1f600}");
+            ui.debug_text_encoding("");
+        });
+        let _ = ctx.render();
+    }
+}
+
+#[test]
+fn test_run_once_only_runs_first_frame() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut runs = Vec::new();
+    for frame in 0..3 {
+        let ui = ctx.new_frame();
+        ui.window("Once").build(|| {
+            ui.run_once("setup", || runs.push(("setup", frame)));
+            ui.run_once("other", || runs.push(("other", frame)));
+        });
+        let _ = ctx.render();
+    }
+    assert_eq!(runs, [("setup", 0), ("other", 0)]);
+}
+
+#[test]
+fn test_animate_bool_converges() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.io_mut().delta_time = 1.0 / 60.0;
+    let mut values = Vec::new();
+    for frame in 0..120 {
+        let ui = ctx.new_frame();
+        ui.window("Animate").build(|| {
+            values.push(ui.animate_bool("open", frame > 0, 10.0));
+        });
+        let _ = ctx.render();
+    }
+    // Seeded with the first target, then eases toward the new one without overshooting
+    assert_eq!(values[0], 0.0);
+    assert!(values[1] > 0.0 && values[1] < 0.5);
+    assert!(values.windows(2).all(|w| w[1] >= w[0] && w[1] <= 1.0));
+    assert_eq!(*values.last().unwrap(), 1.0);
+}
+
+#[cfg(feature = "docking")]
+#[test]
+fn test_debug_item_picker() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    {
+        let ui = ctx.new_frame();
+        assert!(!ui.is_debug_item_picker_active());
+        ui.debug_start_item_picker();
+        assert!(ui.is_debug_item_picker_active());
+        let _ = ctx.render();
+    }
+    {
+        // Nothing is hovered, so the picker stays active
+        let ui = ctx.new_frame();
+        ui.window("Picker").build(|| {
+            ui.button("Pick me");
+        });
+        assert!(ui.is_debug_item_picker_active());
+        let _ = ctx.render();
+    }
+    ctx.io_mut().add_key_event(Key::Escape, true);
+    let ui = ctx.new_frame();
+    assert!(!ui.is_debug_item_picker_active());
+    let _ = ctx.render();
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Ui::separator called after the end of the frame")]
+fn test_string_less_widget_after_end_frame_panics() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    ui.separator();
+    ui.end_frame_early();
+    ui.separator();
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Ui::push_item_width called after the end of the frame")]
+fn test_stack_push_after_end_frame_panics() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    ui.end_frame_early();
+    let _width = ui.push_item_width(100.0);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Ui::button called after the end of the frame")]
+fn test_widget_after_end_frame_panics() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    ui.button("Before");
+    ui.end_frame_early();
+    ui.button("After");
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Ui::checkbox called after the end of the frame")]
+fn test_checkbox_after_end_frame_panics() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    ui.end_frame_early();
+    ui.checkbox("After", &mut false);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Image::build called after the end of the frame")]
+fn test_widget_builder_after_end_frame_panics() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    ui.end_frame_early();
+    Image::new(TextureId::new(1), [16.0, 16.0]).build(ui);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+// `const` thread local initializers need a newer Rust than the MSRV
+#[allow(clippy::missing_const_for_thread_local)]
+fn test_widget_after_end_frame_panics_at_the_call() {
+    use std::panic;
+
+    thread_local!(static LINE: cell::Cell<Option<u32>> = cell::Cell::new(None));
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    ui.end_frame_early();
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let line = info
+            .location()
+            .filter(|l| l.file() == file!())
+            .map(|l| l.line());
+        LINE.with(|cell| cell.set(line));
+    }));
+    let line = line!() + 1;
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| ui.checkbox("After", &mut false)));
+    panic::set_hook(hook);
+    assert!(result.is_err());
+    assert_eq!(LINE.with(|cell| cell.get()), Some(line));
+}
@@ -1,4 +1,5 @@
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use crate::sys;
 use crate::Ui;
@@ -122,6 +123,15 @@ impl<'ui> ListClipperToken<'ui> {
     pub fn iter(self) -> ListClipperIterator<'ui> {
         ListClipperIterator::new(self)
     }
+
+    /// Get an iterator which outputs the visible items as ranges, one per
+    /// call to `step`. Useful when a whole chunk can be submitted at once.
+    pub fn ranges(self) -> ListClipperRanges<'ui> {
+        ListClipperRanges {
+            list_clipper: self,
+            exhausted: false,
+        }
+    }
 }
 
 impl<'ui> Drop for ListClipperToken<'ui> {
@@ -132,6 +142,26 @@ impl<'ui> Drop for ListClipperToken<'ui> {
     }
 }
 
+/// Iterator over the visible item ranges, see [`ListClipperToken::ranges`]
+pub struct ListClipperRanges<'ui> {
+    list_clipper: ListClipperToken<'ui>,
+    exhausted: bool,
+}
+
+impl Iterator for ListClipperRanges<'_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted || !self.list_clipper.step() {
+            self.exhausted = true;
+            return None;
+        }
+        let start = self.list_clipper.display_start() as usize;
+        let end = self.list_clipper.display_end() as usize;
+        Some(start..end)
+    }
+}
+
 pub struct ListClipperIterator<'ui> {
     list_clipper: ListClipperToken<'ui>,
     exhausted: bool,
@@ -202,6 +232,17 @@ impl Iterator for ListClipperIterator<'_> {
     }
 }
 
+impl Ui {
+    /// Begins configuring a [`ListClipper`] for `items_count` items.
+    ///
+    /// Call [`begin`](ListClipper::begin) on the result, optionally after
+    /// setting [`items_height`](ListClipper::items_height).
+    #[doc(alias = "ListClipper")]
+    pub fn list_clipper(&self, items_count: usize) -> ListClipper {
+        ListClipper::new(items_count as i32)
+    }
+}
+
 #[test]
 fn cpp_style_usage() {
     // Setup
@@ -268,3 +309,31 @@ fn iterator_usage() {
     // Should be consistent with size in `cpp_style_usage`
     assert_eq!(ticks, 44);
 }
+
+#[test]
+fn ranges_usage() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.frame();
+
+    let _window = ui
+        .window("Example")
+        .position([0.0, 0.0], crate::Condition::Always)
+        .size([100.0, 800.0], crate::Condition::Always)
+        .begin();
+
+    let mut ticks = 0;
+    let mut last_end = 0;
+    let height = ui.text_line_height_with_spacing();
+    let clipper = ui.list_clipper(1_000_000).items_height(height).begin(ui);
+    for range in clipper.ranges() {
+        assert!(range.start >= last_end);
+        last_end = range.end;
+        for _ in range {
+            ui.text("...");
+            ticks += 1;
+        }
+    }
+
+    // Only the rows fitting in the window are submitted
+    assert!(ticks > 0 && ticks < 50, "{}", ticks);
+}
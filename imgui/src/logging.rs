@@ -0,0 +1,215 @@
+use std::path::Path;
+
+use crate::sys;
+use crate::Ui;
+
+/// Tracks an active log started with one of the `Ui::log_to_*` functions, that can be
+/// finished by calling `.end()` or by dropping.
+#[must_use]
+pub struct LogToken<'ui>(&'ui Ui);
+
+impl<'ui> LogToken<'ui> {
+    /// Starts a log with `start`, unless a log is already active.
+    fn start(ui: &'ui Ui, start: impl FnOnce()) -> Option<Self> {
+        if ui.log_active.get() {
+            return None;
+        }
+        #[cfg(feature = "docking")]
+        {
+            // also covers logs started by `Ui::log_buttons`
+            if unsafe { (*sys::igGetCurrentContext()).LogEnabled } {
+                return None;
+            }
+        }
+        start();
+        ui.log_active.set(true);
+        Some(LogToken(ui))
+    }
+
+    /// Finishes the log, e.g. copying the captured text to the clipboard.
+    #[inline]
+    pub fn end(self) {
+        // left empty for drop
+    }
+}
+
+impl Drop for LogToken<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::igLogFinish() };
+        self.0.log_active.set(false);
+    }
+}
+
+/// # Logging
+///
+/// Dear ImGui can capture the text that widgets render, e.g. to let users copy the contents of
+/// a panel. While a log is active, all text rendered by widgets, plus [`Ui::log_text`], is
+/// captured, and tree nodes and collapsing headers up to `auto_open_depth` levels deep (or all
+/// of them by default with `None`) are opened so their contents are captured too.
+///
+/// Only one log can be active at a time: the `Ui::log_to_*` functions return `None` while
+/// another log is active. Without the `docking` feature they can only detect logs started by
+/// them, not ones started by [`Ui::log_buttons`]: they then return a token although no log was
+/// started, and dropping it finishes the log started by the button.
+impl Ui {
+    /// Starts capturing text to the clipboard, which is set when the log is finished.
+    ///
+    /// Returns `None` if a log is already active. Without the `docking` feature, only logs
+    /// started by the `log_to_*` functions are detected.
+    #[doc(alias = "LogToClipboard")]
+    #[track_caller]
+    pub fn log_to_clipboard(&self, auto_open_depth: Option<u32>) -> Option<LogToken<'_>> {
+        self.assert_in_frame("Ui::log_to_clipboard");
+        LogToken::start(self, || unsafe {
+            sys::igLogToClipboard(depth(auto_open_depth))
+        })
+    }
+    /// Starts capturing text to the standard output.
+    ///
+    /// Returns `None` if a log is already active. Without the `docking` feature, only logs
+    /// started by the `log_to_*` functions are detected.
+    #[doc(alias = "LogToTTY")]
+    #[track_caller]
+    pub fn log_to_tty(&self, auto_open_depth: Option<u32>) -> Option<LogToken<'_>> {
+        self.assert_in_frame("Ui::log_to_tty");
+        LogToken::start(self, || unsafe { sys::igLogToTTY(depth(auto_open_depth)) })
+    }
+    /// Starts capturing text to the end of a file, or to the
+    /// [log filename](crate::Context::set_log_filename) of the context if `path` is `None`.
+    ///
+    /// Returns `None` if a log is already active (without the `docking` feature, only logs
+    /// started by the `log_to_*` functions are detected), or if there's no log filename or the
+    /// file can't be opened for appending.
+    #[doc(alias = "LogToFile")]
+    #[track_caller]
+    pub fn log_to_file(
+        &self,
+        auto_open_depth: Option<u32>,
+        path: Option<&Path>,
+    ) -> Option<LogToken<'_>> {
+        self.assert_in_frame("Ui::log_to_file");
+        let path = match path {
+            Some(path) => path.to_owned(),
+            None => {
+                let filename = self.io().log_filename;
+                if filename.is_null() {
+                    return None;
+                }
+                let filename = unsafe { std::ffi::CStr::from_ptr(filename) };
+                Path::new(filename.to_str().ok()?).to_owned()
+            }
+        };
+        let path_str = path.to_str()?;
+        if self.log_active.get() {
+            return None;
+        }
+        // Dear ImGui asserts if it fails to open the file, so check first
+        std::fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(&path)
+            .ok()?;
+        LogToken::start(self, || unsafe {
+            sys::igLogToFile(depth(auto_open_depth), self.scratch_txt(path_str))
+        })
+    }
+    /// Adds text to the active log, without rendering it.
+    ///
+    /// Does nothing if no log is active.
+    #[doc(alias = "LogText")]
+    #[track_caller]
+    pub fn log_text(&self, text: impl AsRef<str>) {
+        self.assert_in_frame("Ui::log_text");
+        unsafe { sys::igLogText(b"%s\0".as_ptr() as *const _, self.scratch_txt(text)) }
+    }
+    /// Renders buttons to start logging to the clipboard, the standard output or the log file,
+    /// for the current window.
+    ///
+    /// A log started by these buttons is finished by the end of the current window (unless it's
+    /// a child window), or by [`log_finish`](Self::log_finish).
+    #[doc(alias = "LogButtons")]
+    #[track_caller]
+    pub fn log_buttons(&self) {
+        self.assert_in_frame("Ui::log_buttons");
+        unsafe { sys::igLogButtons() }
+    }
+    /// Finishes the active log, if any, e.g. one started by [`log_buttons`](Self::log_buttons).
+    ///
+    /// Logs started by the `log_to_*` functions are also finished by dropping their
+    /// [`LogToken`].
+    #[doc(alias = "LogFinish")]
+    #[track_caller]
+    pub fn log_finish(&self) {
+        self.assert_in_frame("Ui::log_finish");
+        unsafe { sys::igLogFinish() }
+    }
+}
+
+fn depth(auto_open_depth: Option<u32>) -> i32 {
+    auto_open_depth.map_or(-1, |depth| depth as i32)
+}
+
+#[test]
+fn test_log_to_clipboard() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.set_clipboard_backend(crate::clipboard::MockClipboard(None));
+    let ui = ctx.new_frame();
+    ui.window("Panel").build(|| {
+        ui.text("Not captured");
+        let log = ui.log_to_clipboard(None).unwrap();
+        ui.text("Position: 10, 20");
+        ui.log_text("(logged only)");
+        if let Some(_node) = ui.tree_node("Details") {
+            ui.text("Nested");
+        }
+        log.end();
+        ui.text("Not captured either");
+    });
+    let text = ui.clipboard_text().unwrap();
+    assert!(text.contains("Position: 10, 20"));
+    assert!(text.contains("(logged only)"));
+    // Tree nodes are opened while logging
+    assert!(text.contains("Nested"));
+    assert!(!text.contains("Not captured"));
+    let _ = ctx.render();
+}
+
+#[test]
+fn test_nested_log_keeps_outer_log() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.set_clipboard_backend(crate::clipboard::MockClipboard(None));
+    let ui = ctx.new_frame();
+    ui.window("Panel").build(|| {
+        let log = ui.log_to_clipboard(None).unwrap();
+        ui.text("Before");
+        assert!(ui.log_to_clipboard(None).is_none());
+        assert!(ui.log_to_tty(None).is_none());
+        ui.text("After");
+        log.end();
+        ui.text("Not captured");
+        let text = ui.clipboard_text().unwrap();
+        assert!(text.contains("Before"));
+        assert!(text.contains("After"));
+        assert!(!text.contains("Not captured"));
+        // the log can be started again once the first one finished
+        assert!(ui.log_to_clipboard(None).is_some());
+    });
+    let _ = ctx.render();
+}
+
+#[test]
+fn test_log_finish_ends_the_active_log() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.set_clipboard_backend(crate::clipboard::MockClipboard(None));
+    let ui = ctx.new_frame();
+    ui.window("Panel").build(|| {
+        let _log = ui.log_to_clipboard(None).unwrap();
+        ui.text("Captured");
+        ui.log_finish();
+        ui.text("Not captured");
+        let text = ui.clipboard_text().unwrap();
+        assert!(text.contains("Captured"));
+        assert!(!text.contains("Not captured"));
+    });
+    let _ = ctx.render();
+}
@@ -67,7 +67,9 @@ impl<'ui, 'p, Label: AsRef<str>, Overlay: AsRef<str>> PlotHistogram<'ui, 'p, Lab
         self
     }
 
+    #[track_caller]
     pub fn build(self) {
+        self.ui.assert_in_frame("PlotHistogram::build");
         unsafe {
             let (label, overlay_text) = self.ui.scratch_txt_with_opt(self.label, self.overlay_text);
 
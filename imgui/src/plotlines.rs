@@ -67,7 +67,9 @@ impl<'ui, 'p, Label: AsRef<str>, Overlay: AsRef<str>> PlotLines<'ui, 'p, Label,
         self
     }
 
+    #[track_caller]
     pub fn build(self) {
+        self.ui.assert_in_frame("PlotLines::build");
         unsafe {
             let (label, overlay) = self.ui.scratch_txt_with_opt(self.label, self.overlay_text);
 
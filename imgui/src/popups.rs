@@ -1,8 +1,21 @@
 use std::ptr;
 
+use bitflags::bitflags;
+
 use crate::sys;
 use crate::window::WindowFlags;
-use crate::Ui;
+use crate::{MouseButton, Ui};
+
+bitflags!(
+    /// Flags for opening context popups
+    #[repr(transparent)]
+    pub struct PopupFlags: u32 {
+        /// Don't open the popup if there's already a popup at the same level of the popup stack
+        const NO_OPEN_OVER_EXISTING_POPUP = sys::ImGuiPopupFlags_NoOpenOverExistingPopup;
+        /// Don't open the popup when clicking over an item, only over empty space
+        const NO_OPEN_OVER_ITEMS = sys::ImGuiPopupFlags_NoOpenOverItems;
+    }
+);
 
 /// Create a modal pop-up.
 ///
@@ -121,7 +134,9 @@ impl<'ui, 'p, Label: AsRef<str>> PopupModal<'ui, 'p, Label> {
     /// Consume and draw the PopupModal.
     /// Returns the result of the closure, if it is called.
     #[doc(alias = "BeginPopupModal")]
+    #[track_caller]
     pub fn build<T, F: FnOnce() -> T>(self, f: F) -> Option<T> {
+        self.ui.assert_in_frame("PopupModal::build");
         self.begin_popup().map(|_popup| f())
     }
 
@@ -131,7 +146,9 @@ impl<'ui, 'p, Label: AsRef<str>> PopupModal<'ui, 'p, Label> {
     /// This should be called *per frame*, whereas [`Ui::open_popup`]
     /// should be called *once* when you want to actual create the popup.
     #[doc(alias = "BeginPopupModal")]
+    #[track_caller]
     pub fn begin_popup(self) -> Option<PopupToken<'ui>> {
+        self.ui.assert_in_frame("PopupModal::begin_popup");
         let render = unsafe {
             sys::igBeginPopupModal(
                 self.ui.scratch_txt(self.label),
@@ -163,7 +180,9 @@ impl Ui {
     ///
     /// The confusing aspect to popups is that ImGui holds control over the popup itself.
     #[doc(alias = "OpenPopup")]
+    #[track_caller]
     pub fn open_popup(&self, str_id: impl AsRef<str>) {
+        self.assert_in_frame("Ui::open_popup");
         unsafe { sys::igOpenPopup_Str(self.scratch_txt(str_id), 0) };
     }
 
@@ -172,7 +191,9 @@ impl Ui {
     /// This should be called *per frame*, whereas [`open_popup`](Self::open_popup) should be called *once*
     /// to signal that this popup is active.
     #[doc(alias = "BeginPopup")]
+    #[track_caller]
     pub fn begin_popup(&self, str_id: impl AsRef<str>) -> Option<PopupToken<'_>> {
+        self.assert_in_frame("Ui::begin_popup");
         let render = unsafe {
             sys::igBeginPopup(self.scratch_txt(str_id), WindowFlags::empty().bits() as i32)
         };
@@ -189,10 +210,12 @@ impl Ui {
     /// This should be called *per frame*, whereas [`open_popup`](Self::open_popup) should be called *once*
     /// to signal that this popup is active.
     #[doc(alias = "BeginPopup")]
+    #[track_caller]
     pub fn popup<F>(&self, str_id: impl AsRef<str>, f: F)
     where
         F: FnOnce(),
     {
+        self.assert_in_frame("Ui::popup");
         if let Some(_t) = self.begin_popup(str_id) {
             f();
         }
@@ -201,11 +224,13 @@ impl Ui {
     /// Creates a [PopupModal], and runs a closure on it.
     ///
     /// To customize the behavior of this [PopupModal], use [`modal_popup_config`](Self::modal_popup_config).
+    #[track_caller]
     pub fn modal_popup<Label, Func, R>(&self, str_id: Label, f: Func) -> Option<R>
     where
         Label: AsRef<str>,
         Func: FnOnce() -> R,
     {
+        self.assert_in_frame("Ui::modal_popup");
         PopupModal {
             ui: self,
             label: str_id,
@@ -218,7 +243,9 @@ impl Ui {
     /// Creates a [PopupModal], returning a drop token.
     ///
     /// To customize the behavior of this [PopupModal], use [`modal_popup_config`](Self::modal_popup_config).
+    #[track_caller]
     pub fn begin_modal_popup<Label: AsRef<str>>(&self, str_id: Label) -> Option<PopupToken<'_>> {
+        self.assert_in_frame("Ui::begin_modal_popup");
         PopupModal {
             ui: self,
             label: str_id,
@@ -241,10 +268,62 @@ impl Ui {
         }
     }
 
+    /// Opens and begins a popup when `mouse_button` is clicked over empty space, i.e. when
+    /// no window is hovered, e.g. for a context menu of the background or canvas of an
+    /// application.
+    ///
+    /// The popup opens when the button is released. Clicks over windows don't open it, except
+    /// over windows with [`WindowFlags::NO_INPUTS`], which are never hovered. The popup id
+    /// defaults to `"void_context"` if `str_id` is `None`.
+    ///
+    /// Returns a [`PopupToken`] while the popup is open.
+    #[doc(alias = "BeginPopupContextVoid")]
+    #[track_caller]
+    pub fn begin_popup_context_void(
+        &self,
+        str_id: Option<&str>,
+        mouse_button: MouseButton,
+        flags: PopupFlags,
+    ) -> Option<PopupToken<'_>> {
+        self.assert_in_frame("Ui::begin_popup_context_void");
+        let render = unsafe {
+            sys::igBeginPopupContextVoid(
+                self.scratch_txt_opt(str_id),
+                (flags.bits() | mouse_button as u32) as i32,
+            )
+        };
+
+        if render {
+            Some(PopupToken::new(self))
+        } else {
+            None
+        }
+    }
+
+    /// Closes all popups, including modal popups, except the ones that contain the current
+    /// window.
+    ///
+    /// Called from a regular window, this closes all popups, e.g. when the user switches to
+    /// another document. Called from within a popup, this only closes the popups opened from
+    /// it. Between frames, use [`Context::close_all_popups`](crate::Context::close_all_popups)
+    /// instead. To close the popup being built, use
+    /// [`close_current_popup`](Self::close_current_popup).
+    ///
+    /// **Unstable**, see [Unstable APIs](crate#unstable-apis).
+    #[cfg(feature = "docking")]
+    #[doc(alias = "ClosePopupsOverWindow")]
+    #[track_caller]
+    pub fn close_popups_over_window(&self) {
+        self.assert_in_frame("Ui::close_popups_over_window");
+        unsafe { sys::igClosePopupsOverWindow(sys::igGetCurrentWindow(), true) }
+    }
+
     /// Close a popup. Should be called within the closure given as argument to
     /// [`Ui::popup`] or [`Ui::modal_popup`].
     #[doc(alias = "CloseCurrentPopup")]
+    #[track_caller]
     pub fn close_current_popup(&self) {
+        self.assert_in_frame("Ui::close_current_popup");
         unsafe { sys::igCloseCurrentPopup() };
     }
 }
@@ -257,3 +336,93 @@ create_token!(
     /// to drop on its own.
     drop { sys::igEndPopup() }
 );
+
+#[test]
+fn test_begin_popup_context_void_opens_over_empty_space() {
+    fn right_click_at(pos: [f32; 2]) -> bool {
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        ctx.io_mut().mouse_pos = pos;
+        let mut opened = false;
+        for frame in 0..4 {
+            ctx.io_mut().mouse_down[1] = frame == 2;
+            let ui = ctx.new_frame();
+            ui.window("Tools")
+                .position([0.0, 0.0], crate::Condition::Always)
+                .size([200.0, 200.0], crate::Condition::Always)
+                .build(|| {});
+            if let Some(_popup) =
+                ui.begin_popup_context_void(None, MouseButton::Right, PopupFlags::empty())
+            {
+                ui.text("Canvas menu");
+                opened = true;
+            }
+            let _ = ctx.render();
+        }
+        opened
+    }
+    assert!(right_click_at([500.0, 500.0]));
+    // Clicks over a window don't open it
+    assert!(!right_click_at([100.0, 100.0]));
+}
+
+#[cfg(all(test, feature = "docking"))]
+fn open_popup_count() -> i32 {
+    unsafe { (*sys::igGetCurrentContext()).OpenPopupStack.Size }
+}
+
+#[cfg(feature = "docking")]
+#[test]
+fn test_close_popups_over_window() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut opened = Vec::new();
+    for frame in 0..5 {
+        let ui = ctx.new_frame();
+        ui.window("Document").build(|| {
+            if frame == 1 {
+                ui.open_popup("menu");
+            }
+            if frame == 3 {
+                ui.close_popups_over_window();
+            }
+            opened.push(ui.begin_popup("menu").is_some());
+        });
+        let _ = ctx.render();
+    }
+    assert_eq!(opened, [false, true, true, false, false]);
+    assert_eq!(open_popup_count(), 0);
+}
+
+#[cfg(feature = "docking")]
+#[test]
+fn test_close_all_popups() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    // Closing without any open popup does nothing
+    ctx.close_all_popups();
+    for frame in 0..4 {
+        let ui = ctx.new_frame();
+        ui.window("Document").build(|| {
+            if frame == 1 {
+                ui.open_popup("menu");
+            }
+            if let Some(_menu) = ui.begin_popup("menu") {
+                // A modal opened from the menu
+                if frame == 2 {
+                    ui.open_popup("modal");
+                }
+                if let Some(_modal) = ui.begin_modal_popup("modal") {
+                    ui.text("Modal");
+                }
+            }
+        });
+        let _ = ctx.render();
+    }
+    assert_eq!(open_popup_count(), 2);
+
+    ctx.close_all_popups();
+    assert_eq!(open_popup_count(), 0);
+    let ui = ctx.new_frame();
+    ui.window("Document").build(|| {
+        assert!(ui.begin_popup("menu").is_none());
+    });
+    let _ = ctx.render();
+}
@@ -0,0 +1,128 @@
+use std::marker::PhantomData;
+
+use crate::Ui;
+
+/// Tracks a profiling scope started with [`Ui::profile_scope`], that can be ended by calling
+/// `.end()` or by dropping.
+#[must_use]
+pub struct ProfileScopeToken<'ui> {
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan,
+    _ui: PhantomData<&'ui Ui>,
+}
+
+impl ProfileScopeToken<'_> {
+    /// Ends the profiling scope.
+    #[inline]
+    pub fn end(self) {
+        // left empty for drop
+    }
+}
+
+/// # Profiling
+///
+/// With the `tracing` feature, the major phases of a frame are traced as
+/// [`tracing`](https://docs.rs/tracing) spans, so that a profiler subscriber (e.g. for Tracy
+/// or puffin) shows how much of the frame time the UI takes: [`Context::new_frame`] as
+/// `imgui::new_frame`, [`Context::render`] as `imgui::render`, and parts of the widget
+/// submission as `imgui::profile_scope`. Without the feature, none of this costs anything.
+///
+/// [`Context::new_frame`]: crate::Context::new_frame
+/// [`Context::render`]: crate::Context::render
+impl Ui {
+    /// Starts a profiling scope for the widgets submitted until the returned token is dropped.
+    ///
+    /// With the `tracing` feature, this enters an `imgui::profile_scope` span (at the `INFO`
+    /// level) with `name` as its `name` field, as span names have to be known at compile time.
+    /// Without the feature this does nothing.
+    #[inline]
+    pub fn profile_scope(&self, name: &str) -> ProfileScopeToken<'_> {
+        #[cfg(not(feature = "tracing"))]
+        let _ = name;
+        ProfileScopeToken {
+            #[cfg(feature = "tracing")]
+            _span: tracing::info_span!("imgui::profile_scope", name).entered(),
+            _ui: PhantomData,
+        }
+    }
+}
+
+#[test]
+fn test_profile_scope_without_subscriber() {
+    crate::test::headless(|ui| {
+        let scope = ui.profile_scope("Inspector");
+        ui.window("Inspector").build(|| {
+            let _scope = ui.profile_scope("Properties");
+            ui.text("Properties");
+        });
+        scope.end();
+    });
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn test_frame_phases_emit_spans() {
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Records the spans as they are entered, with the `name` field of profile scopes
+    #[derive(Default)]
+    struct Recorder {
+        spans: Mutex<Vec<String>>,
+        entered: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct NameField<'a>(&'a mut String);
+
+    impl Visit for NameField<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "name" {
+                self.0.push_str(&format!("({})", value));
+            }
+        }
+        fn record_debug(&mut self, _: &Field, _: &dyn std::fmt::Debug) {}
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut name = span.metadata().name().to_string();
+            span.record(&mut NameField(&mut name));
+            let mut spans = self.spans.lock().unwrap();
+            spans.push(name);
+            Id::from_u64(spans.len() as u64)
+        }
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, span: &Id) {
+            let name = self.spans.lock().unwrap()[span.into_u64() as usize - 1].clone();
+            self.entered.lock().unwrap().push(name);
+        }
+        fn exit(&self, _: &Id) {}
+    }
+
+    let recorder = Recorder::default();
+    let entered = recorder.entered.clone();
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    tracing::subscriber::with_default(recorder, || {
+        let ui = ctx.new_frame();
+        ui.window("Inspector").build(|| {
+            let _scope = ui.profile_scope("Properties");
+            ui.text("Properties");
+        });
+        let _ = ctx.render();
+    });
+    assert_eq!(
+        *entered.lock().unwrap(),
+        [
+            "imgui::new_frame",
+            "imgui::profile_scope(Properties)",
+            "imgui::render"
+        ]
+    );
+}
@@ -56,11 +56,87 @@ impl DrawData {
     }
     #[inline]
     pub(crate) unsafe fn cmd_lists(&self) -> &[*const DrawList] {
+        if self.cmd_lists.is_null() {
+            // Nothing was rendered
+            return &[];
+        }
         slice::from_raw_parts(
             self.cmd_lists as *const *const DrawList,
             self.cmd_lists_count as usize,
         )
     }
+    /// Returns the draw commands of all draw lists, grouped into one batch per texture.
+    ///
+    /// Batches are ordered by the first use of their texture, and the commands within a batch
+    /// keep their original order and clip rects, so renderers can bind each texture once.
+    /// Note that this changes the order in which overlapping elements are drawn, so it is only
+    /// correct if that order doesn't matter for your UI (e.g. no translucent overlaps between
+    /// textures).
+    ///
+    /// Only [`DrawCmd::Elements`] commands are included; callbacks are skipped. The batches are
+    /// collected into new buffers, the draw data itself is left untouched.
+    pub fn iter_by_texture(&self) -> TextureBatchIterator<'_> {
+        let mut batches: Vec<TextureBatch<'_>> = Vec::new();
+        for draw_list in self.draw_lists() {
+            for cmd in draw_list.commands() {
+                if let DrawCmd::Elements { count, cmd_params } = cmd {
+                    let batch_cmd = TextureBatchCmd {
+                        draw_list,
+                        count,
+                        cmd_params,
+                    };
+                    match batches
+                        .iter_mut()
+                        .find(|batch| batch.texture_id == cmd_params.texture_id)
+                    {
+                        Some(batch) => batch.commands.push(batch_cmd),
+                        None => batches.push(TextureBatch {
+                            texture_id: cmd_params.texture_id,
+                            commands: vec![batch_cmd],
+                        }),
+                    }
+                }
+            }
+        }
+        TextureBatchIterator {
+            iter: batches.into_iter(),
+        }
+    }
+    /// Copies the vertices, indices and commands of all draw lists into single buffers.
+    ///
+    /// The buffers are cleared first, so passing the same `Vec`s every frame reuses their
+    /// allocations. The `vtx_offset` and `idx_offset` of each [`DrawCmd::Elements`] command are
+    /// rewritten to point into the flattened buffers: draw `count` indices starting at
+    /// `idx_offset` in `idx`, adding `vtx_offset` to each index. Callback commands are copied
+    /// unchanged, and still refer to the original draw list.
+    pub fn copy_into(
+        &self,
+        verts: &mut Vec<DrawVert>,
+        idx: &mut Vec<DrawIdx>,
+        cmds: &mut Vec<DrawCmd>,
+    ) {
+        verts.clear();
+        idx.clear();
+        cmds.clear();
+        verts.reserve(self.total_vtx_count as usize);
+        idx.reserve(self.total_idx_count as usize);
+        for draw_list in self.draw_lists() {
+            let (vtx_base, idx_base) = (verts.len(), idx.len());
+            verts.extend_from_slice(draw_list.vtx_buffer());
+            idx.extend_from_slice(draw_list.idx_buffer());
+            cmds.extend(draw_list.commands().map(|cmd| match cmd {
+                DrawCmd::Elements { count, cmd_params } => DrawCmd::Elements {
+                    count,
+                    cmd_params: DrawCmdParams {
+                        vtx_offset: vtx_base + cmd_params.vtx_offset,
+                        idx_offset: idx_base + cmd_params.idx_offset,
+                        ..cmd_params
+                    },
+                },
+                other => other,
+            }));
+        }
+    }
     /// Converts all buffers from indexed to non-indexed, in case you cannot render indexed
     /// buffers.
     ///
@@ -96,6 +172,150 @@ impl<'a> Iterator for DrawListIterator<'a> {
     }
 }
 
+/// Iterator over the texture batches of [`DrawData::iter_by_texture`]
+pub struct TextureBatchIterator<'a> {
+    iter: std::vec::IntoIter<TextureBatch<'a>>,
+}
+
+impl<'a> Iterator for TextureBatchIterator<'a> {
+    type Item = TextureBatch<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+/// All draw commands of a frame using the same texture
+pub struct TextureBatch<'a> {
+    /// The texture used by all commands of this batch
+    pub texture_id: TextureId,
+    /// The [`DrawCmd::Elements`] commands using the texture, in their original order.
+    /// Callback and render state reset commands are skipped
+    pub commands: Vec<TextureBatchCmd<'a>>,
+}
+
+/// A draw command in a [`TextureBatch`], with the draw list owning its vertices and indices
+#[derive(Copy, Clone)]
+pub struct TextureBatchCmd<'a> {
+    /// The draw list the command belongs to, whose buffers `cmd_params` offsets point into
+    pub draw_list: &'a DrawList,
+    /// The number of indices used for this draw command
+    pub count: usize,
+    /// Clip rect, texture and buffer offsets of the command
+    pub cmd_params: DrawCmdParams,
+}
+
+#[test]
+fn test_drawdata_iter_by_texture() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let font_texture = TextureId::new(1);
+    ctx.fonts().tex_id = font_texture;
+    let (first, second) = (TextureId::new(2), TextureId::new(3));
+    let _ = ctx.new_frame();
+    let draw_data = ctx.render();
+    assert_eq!(draw_data.iter_by_texture().count(), 0);
+    let frame = |ctx: &mut crate::Context| {
+        let ui = ctx.new_frame();
+        for (name, x) in [("One", 0.0), ("Two", 200.0)] {
+            ui.window(name)
+                .position([x, 0.0], crate::Condition::Always)
+                .build(|| {
+                    let draw_list = ui.get_window_draw_list();
+                    draw_list.add_image(first, [0.0, 0.0], [8.0, 8.0]).build();
+                    ui.text("text");
+                    draw_list.add_image(second, [0.0, 0.0], [8.0, 8.0]).build();
+                    draw_list.add_image(first, [0.0, 0.0], [8.0, 8.0]).build();
+                });
+        }
+    };
+    // Windows are only drawn once they have been auto-sized
+    frame(&mut ctx);
+    let _ = ctx.render();
+    frame(&mut ctx);
+    let draw_data = ctx.render();
+    let total_cmds: usize = draw_data
+        .draw_lists()
+        .map(|list| list.commands().count())
+        .sum();
+
+    let batches: Vec<_> = draw_data.iter_by_texture().collect();
+    let ids: Vec<_> = batches.iter().map(|batch| batch.texture_id).collect();
+    assert_eq!(ids, [font_texture, first, second]);
+    assert_eq!(
+        batches.iter().map(|b| b.commands.len()).sum::<usize>(),
+        total_cmds
+    );
+    for batch in &batches {
+        for cmd in &batch.commands {
+            assert_eq!(cmd.cmd_params.texture_id, batch.texture_id);
+            let end = cmd.cmd_params.idx_offset + cmd.count;
+            assert!(end <= cmd.draw_list.idx_buffer().len());
+        }
+    }
+    // Both windows use the second texture once, with their own clip rects
+    let second_cmds = &batches[2].commands;
+    assert_eq!(second_cmds.len(), 2);
+    assert!(!std::ptr::eq(
+        second_cmds[0].draw_list,
+        second_cmds[1].draw_list
+    ));
+    assert_ne!(
+        second_cmds[0].cmd_params.clip_rect,
+        second_cmds[1].cmd_params.clip_rect
+    );
+}
+
+#[test]
+fn test_drawdata_copy_into() {
+    fn elements(cmd: &DrawCmd) -> (usize, DrawCmdParams) {
+        match cmd {
+            DrawCmd::Elements { count, cmd_params } => (*count, *cmd_params),
+            _ => panic!("unexpected callback command"),
+        }
+    }
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let (mut verts, mut idx, mut cmds) = (Vec::new(), Vec::new(), Vec::new());
+    for frame in 0..3 {
+        let ui = ctx.new_frame();
+        for (name, x) in [("One", 0.0), ("Two", 200.0)] {
+            ui.window(name)
+                .position([x, 0.0], crate::Condition::Always)
+                .build(|| ui.text("flattened"));
+        }
+        let draw_data = ctx.render();
+        let idx_ptr = idx.as_ptr();
+        draw_data.copy_into(&mut verts, &mut idx, &mut cmds);
+        if frame == 2 {
+            // The same frame again fits in the buffers of the previous one
+            assert_eq!(idx.as_ptr(), idx_ptr);
+        }
+        assert_eq!(verts.len(), draw_data.total_vtx_count as usize);
+        assert_eq!(idx.len(), draw_data.total_idx_count as usize);
+
+        // Each flattened command draws the same vertices as the original one
+        let mut flattened = cmds.iter();
+        for draw_list in draw_data.draw_lists() {
+            for cmd in draw_list.commands() {
+                let (count, original) = elements(&cmd);
+                let (copy_count, copy) = elements(flattened.next().unwrap());
+                assert_eq!(count, copy_count);
+                assert_eq!(original.clip_rect, copy.clip_rect);
+                assert_eq!(original.texture_id, copy.texture_id);
+                let original_idx = &draw_list.idx_buffer()[original.idx_offset..][..count];
+                let copy_idx = &idx[copy.idx_offset..][..count];
+                for (&i, &j) in original_idx.iter().zip(copy_idx) {
+                    assert_eq!(
+                        draw_list.vtx_buffer()[original.vtx_offset + i as usize],
+                        verts[copy.vtx_offset + j as usize]
+                    );
+                }
+            }
+        }
+        assert!(flattened.next().is_none());
+    }
+    assert!(cmds.len() >= 2);
+}
+
 #[test]
 #[cfg(test)]
 fn test_drawdata_memory_layout() {
@@ -1,6 +1,6 @@
 use crate::fonts::atlas::FontId;
 use crate::internal::RawCast;
-use crate::math::MintVec4;
+use crate::math::{MintVec2, MintVec4};
 use crate::style::{StyleColor, StyleVar};
 use crate::sys;
 use crate::Ui;
@@ -15,7 +15,8 @@ impl Ui {
     ///
     /// # Panics
     ///
-    /// Panics if the font atlas does not contain the given font
+    /// Panics if the font atlas does not contain the given font. The `FontId` must come from
+    /// the atlas, e.g. as returned by [`FontAtlas::add_font`](crate::FontAtlas::add_font).
     ///
     /// # Examples
     ///
@@ -32,7 +33,10 @@ impl Ui {
     /// font.pop();
     /// ```
     #[doc(alias = "PushFont")]
+    #[track_caller]
     pub fn push_font(&self, id: FontId) -> FontStackToken<'_> {
+        self.assert_in_frame("Ui::push_font");
+        debug_assert!(!id.0.is_null(), "push_font called with a null FontId");
         let fonts = self.fonts();
         let font = fonts
             .get_font(id)
@@ -56,15 +60,47 @@ impl Ui {
     /// color.pop();
     /// ```
     #[doc(alias = "PushStyleColorVec4")]
+    #[track_caller]
     pub fn push_style_color(
         &self,
         style_color: StyleColor,
         color: impl Into<MintVec4>,
     ) -> ColorStackToken<'_> {
+        self.assert_in_frame("Ui::push_style_color");
         unsafe { sys::igPushStyleColor_Vec4(style_color as i32, color.into().into()) };
         ColorStackToken::new(self)
     }
 
+    /// Changes several style colors at once by pushing them to the color stack in order.
+    ///
+    /// Returns a `MultiColorToken` that pops all of them when `.pop()` is called or when
+    /// it is dropped. Pushing the same color more than once is allowed; the last value wins
+    /// until the token is popped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let colors = ui.push_style_colors(&[
+    ///     (StyleColor::Button, [0.6, 0.1, 0.1, 1.0]),
+    ///     (StyleColor::ButtonHovered, [0.7, 0.2, 0.2, 1.0]),
+    ///     (StyleColor::ButtonActive, [0.8, 0.3, 0.3, 1.0]),
+    /// ]);
+    /// ui.button("Delete");
+    /// colors.pop();
+    /// ```
+    #[doc(alias = "PushStyleColorVec4")]
+    #[track_caller]
+    pub fn push_style_colors(&self, colors: &[(StyleColor, [f32; 4])]) -> MultiColorToken<'_> {
+        self.assert_in_frame("Ui::push_style_colors");
+        for &(style_color, color) in colors {
+            unsafe { sys::igPushStyleColor_Vec4(style_color as i32, color.into()) };
+        }
+        MultiColorToken::new(self, colors.len())
+    }
+
     /// Changes a style variable by pushing a change to the style stack.
     ///
     /// Returns a `StyleStackToken` that can be popped by calling `.end()`
@@ -81,7 +117,9 @@ impl Ui {
     /// style.pop();
     /// ```
     #[doc(alias = "PushStyleVar")]
+    #[track_caller]
     pub fn push_style_var(&self, style_var: StyleVar) -> StyleStackToken<'_> {
+        self.assert_in_frame("Ui::push_style_var");
         unsafe { push_style_var(style_var) };
         StyleStackToken::new(self)
     }
@@ -119,6 +157,42 @@ impl ColorStackToken<'_> {
     }
 }
 
+/// Tracks several colors pushed to the color stack that can be popped by calling `.end()`
+/// or by dropping.
+#[must_use]
+pub struct MultiColorToken<'a>(std::marker::PhantomData<&'a Ui>, usize);
+
+impl<'a> MultiColorToken<'a> {
+    /// Creates a new token type.
+    pub(crate) fn new(_: &'a crate::Ui, count: usize) -> Self {
+        Self(std::marker::PhantomData, count)
+    }
+
+    /// Returns the number of colors this token pops.
+    pub fn count(&self) -> usize {
+        self.1
+    }
+
+    /// Pops all changes from the color stack.
+    #[inline]
+    pub fn end(self) {
+        // left empty for drop
+    }
+
+    /// Pops all changes from the color stack.
+    pub fn pop(self) {
+        self.end()
+    }
+}
+
+impl Drop for MultiColorToken<'_> {
+    fn drop(&mut self) {
+        if self.1 > 0 {
+            unsafe { sys::igPopStyleColor(self.1 as i32) }
+        }
+    }
+}
+
 create_token!(
     /// Tracks a style pushed to the style stack that can be popped by calling `.end()`
     /// or by dropping.
@@ -189,7 +263,9 @@ impl Ui {
     /// - `< 0.0`: `item_width` pixels relative to the right of window (-1.0 always aligns width to
     /// the right side)
     #[doc(alias = "PushItemWith")]
+    #[track_caller]
     pub fn push_item_width(&self, item_width: f32) -> ItemWidthStackToken<'_> {
+        self.assert_in_frame("Ui::push_item_width");
         unsafe { sys::igPushItemWidth(item_width) };
         ItemWidthStackToken::new(self)
     }
@@ -200,14 +276,22 @@ impl Ui {
     /// - `< 0.0`: `item_width` pixels relative to the right of window (-1.0 always aligns width to
     /// the right side)
     #[doc(alias = "SetNextItemWidth")]
+    #[track_caller]
     pub fn set_next_item_width(&self, item_width: f32) {
+        self.assert_in_frame("Ui::set_next_item_width");
         unsafe { sys::igSetNextItemWidth(item_width) };
     }
     /// Returns the width of the item given the pushed settings and the current cursor position.
     ///
-    /// This is NOT necessarily the width of last item.
+    /// This is NOT necessarily the width of last item. Negative widths from
+    /// [push_item_width](Self::push_item_width) or [set_next_item_width](Self::set_next_item_width)
+    /// are resolved against [content_region_avail](Self::content_region_avail), so e.g. with
+    /// `-1.0` this is the available width minus one pixel. To split a line between two items,
+    /// push `(ui.calc_item_width() - ui.clone_style().item_spacing[0]) / 2.0` for both.
     #[doc(alias = "CalcItemWidth")]
+    #[track_caller]
     pub fn calc_item_width(&self) -> f32 {
+        self.assert_in_frame("Ui::calc_item_width");
         unsafe { sys::igCalcItemWidth() }
     }
 
@@ -220,7 +304,9 @@ impl Ui {
     /// Returns a `TextWrapPosStackToken`. The pushed position item is popped when either
     /// `TextWrapPosStackToken` goes out of scope, or `.end()` is called.
     #[doc(alias = "PushTextWrapPos")]
+    #[track_caller]
     pub fn push_text_wrap_pos(&self) -> TextWrapPosStackToken<'_> {
+        self.assert_in_frame("Ui::push_text_wrap_pos");
         self.push_text_wrap_pos_with_pos(0.0)
     }
 
@@ -232,8 +318,22 @@ impl Ui {
     /// - `> 0.0`: wrap at `wrap_pos_x` position in window local space
     /// - `= 0.0`: wrap to end of window (or column)
     /// - `< 0.0`: no wrapping
+    ///
+    /// Window local space is the space of [`cursor_pos`](Self::cursor_pos): 0.0 is the left
+    /// edge of the window, and scrolling is taken into account. To wrap at a given width from
+    /// the current position, add it to the cursor position:
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let _wrap = ui.push_text_wrap_pos_with_pos(ui.cursor_pos()[0] + 200.0);
+    /// ui.text("This long help text wraps after 200 pixels, whatever the window width is.");
+    /// ```
     #[doc(alias = "PushTextWrapPos")]
+    #[track_caller]
     pub fn push_text_wrap_pos_with_pos(&self, wrap_pos_x: f32) -> TextWrapPosStackToken<'_> {
+        self.assert_in_frame("Ui::push_text_wrap_pos_with_pos");
         unsafe { sys::igPushTextWrapPos(wrap_pos_x) };
         TextWrapPosStackToken::new(self)
     }
@@ -244,7 +344,9 @@ impl Ui {
     ///
     /// Returns a [PushAllowKeyboardFocusToken] that should be dropped.
     #[doc(alias = "PushAllowKeyboardFocus")]
+    #[track_caller]
     pub fn push_allow_keyboard_focus(&self, allow: bool) -> PushAllowKeyboardFocusToken<'_> {
+        self.assert_in_frame("Ui::push_allow_keyboard_focus");
         unsafe { sys::igPushAllowKeyboardFocus(allow) };
         PushAllowKeyboardFocusToken::new(self)
     }
@@ -254,13 +356,44 @@ impl Ui {
     /// Note that you can call IsItemActive() after any Button() to tell if the
     /// button is held in the current frame.
     ///
+    /// This is how held buttons (e.g. +/- steppers) fire repeatedly: Dear ImGui only exposes
+    /// the button repeat flag through this stack.
+    ///
     /// Returns a [PushButtonRepeatToken] that should be dropped.
-    #[doc(alias = "PushAllowKeyboardFocus")]
+    #[doc(alias = "PushButtonRepeat")]
+    #[track_caller]
     pub fn push_button_repeat(&self, allow: bool) -> PushButtonRepeatToken<'_> {
+        self.assert_in_frame("Ui::push_button_repeat");
         unsafe { sys::igPushButtonRepeat(allow) };
         PushButtonRepeatToken::new(self)
     }
 
+    /// Restricts the current window to a clipping rectangle, in screen coordinates, by pushing
+    /// it to the clip rect stack.
+    ///
+    /// Unlike [`DrawListMut::with_clip_rect`](crate::DrawListMut::with_clip_rect), which only
+    /// clips rendering, this also affects widget logic: items outside the rectangle are culled
+    /// (they report not being visible and skip rendering) and can't be hovered or clicked.
+    /// With `intersect_with_current` the rectangle is intersected with the current clipping
+    /// rectangle, which is almost always what you want.
+    ///
+    /// Returns a `ClipRectStackToken`. The pushed rectangle is popped when either
+    /// `ClipRectStackToken` goes out of scope, or `.end()` is called.
+    #[doc(alias = "PushClipRect")]
+    #[track_caller]
+    pub fn push_clip_rect(
+        &self,
+        min: impl Into<MintVec2>,
+        max: impl Into<MintVec2>,
+        intersect_with_current: bool,
+    ) -> ClipRectStackToken<'_> {
+        self.assert_in_frame("Ui::push_clip_rect");
+        unsafe {
+            sys::igPushClipRect(min.into().into(), max.into().into(), intersect_with_current)
+        };
+        ClipRectStackToken::new(self)
+    }
+
     /// Changes an item flag by pushing a change to the item flag stack.
     ///
     /// Returns a `ItemFlagsStackToken` that may be popped by calling `.pop()`
@@ -278,7 +411,9 @@ impl Ui {
         since = "0.9.0",
         note = "use `push_allow_keyboard_focus` or `push_button_repeat` instead"
     )]
+    #[track_caller]
     pub fn push_item_flag(&self, item_flag: ItemFlag) -> ItemFlagsStackToken<'_> {
+        self.assert_in_frame("Ui::push_item_flag");
         use self::ItemFlag::*;
         match item_flag {
             AllowKeyboardFocus(v) => unsafe { sys::igPushAllowKeyboardFocus(v) },
@@ -323,6 +458,13 @@ create_token!(
     drop { sys::igPopButtonRepeat() }
 );
 
+create_token!(
+    pub struct ClipRectStackToken<'ui>;
+
+    #[doc(alias = "PopClipRect")]
+    drop { sys::igPopClipRect() }
+);
+
 /// Tracks a change pushed to the item flags stack.
 ///
 /// The "item flags" stack was a concept invented in imgui-rs that doesn't have an
@@ -359,6 +501,69 @@ impl Drop for ItemFlagsStackToken<'_> {
     }
 }
 
+#[cfg(feature = "docking")]
+bitflags::bitflags! {
+    /// Item flags that can be pushed with [`Ui::push_item_flags`]
+    #[repr(transparent)]
+    pub struct ItemFlags: u32 {
+        /// Disables keyboard tabbing to the item
+        const NO_TAB_STOP = sys::ImGuiItemFlags_NoTabStop;
+        /// The item fires repeatedly while held, see [`Ui::push_button_repeat`]
+        const BUTTON_REPEAT = sys::ImGuiItemFlags_ButtonRepeat;
+        /// Disables interaction with the item, without the dimmed look of
+        /// [`Ui::begin_disabled`]
+        const DISABLED = sys::ImGuiItemFlags_Disabled;
+        /// Disables keyboard/gamepad navigation to the item
+        const NO_NAV = sys::ImGuiItemFlags_NoNav;
+        /// Prevents the item from being focused by default when its window appears
+        const NO_NAV_DEFAULT_FOCUS = sys::ImGuiItemFlags_NoNavDefaultFocus;
+        /// Clicking a selectable doesn't close the popup it is in
+        const SELECTABLE_DONT_CLOSE_POPUP = sys::ImGuiItemFlags_SelectableDontClosePopup;
+        /// Shows a "mixed value" state, supported by checkboxes and radio buttons
+        const MIXED_VALUE = sys::ImGuiItemFlags_MixedValue;
+        /// Prevents edits of input widgets, which can still be scrolled and copied from
+        const READ_ONLY = sys::ImGuiItemFlags_ReadOnly;
+    }
+}
+
+/// # Item flags stack
+///
+/// **Unstable**, see [Unstable APIs](crate#unstable-apis).
+#[cfg(feature = "docking")]
+impl Ui {
+    /// Enables or disables the given item flags for all following items by pushing them to the
+    /// item flags stack.
+    ///
+    /// Returns a `PushItemFlagsToken` that can be popped by calling `.end()` or by dropping.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let _flags = ui.push_item_flags(ItemFlags::NO_TAB_STOP | ItemFlags::NO_NAV, true);
+    /// ui.button("Skipped by keyboard navigation");
+    /// ```
+    #[doc(alias = "PushItemFlag")]
+    #[track_caller]
+    pub fn push_item_flags(&self, flags: ItemFlags, enabled: bool) -> PushItemFlagsToken<'_> {
+        self.assert_in_frame("Ui::push_item_flags");
+        unsafe { sys::igPushItemFlag(flags.bits() as sys::ImGuiItemFlags, enabled) };
+        PushItemFlagsToken::new(self)
+    }
+}
+
+#[cfg(feature = "docking")]
+create_token!(
+    /// Tracks a change pushed to the item flags stack that can be popped by calling `.end()`
+    /// or by dropping.
+    pub struct PushItemFlagsToken<'ui>;
+
+    #[doc(alias = "PopItemFlag")]
+    drop { sys::igPopItemFlag() }
+);
+
 create_token!(
     /// Tracks an ID pushed to the ID stack that can be popped by calling `.pop()`
     /// or by dropping. See [`crate::Ui::push_id`] for more details.
@@ -457,7 +662,9 @@ impl<'ui> Ui {
     /// });
     /// ```
     #[doc(alias = "PushId")]
+    #[track_caller]
     pub fn push_id(&self, s: impl AsRef<str>) -> IdStackToken<'_> {
+        self.assert_in_frame("Ui::push_id");
         unsafe {
             let s = s.as_ref();
             let start = s.as_ptr() as *const c_char;
@@ -476,7 +683,9 @@ impl<'ui> Ui {
     ///
     /// [push_id]: Self::push_id
     #[doc(alias = "PushId")]
+    #[track_caller]
     pub fn push_id_usize(&self, id: usize) -> IdStackToken<'_> {
+        self.assert_in_frame("Ui::push_id_usize");
         unsafe { sys::igPushID_Ptr(id as *const _) }
         IdStackToken::new(self)
     }
@@ -490,7 +699,9 @@ impl<'ui> Ui {
     ///
     /// [push_id]: Self::push_id
     #[doc(alias = "PushId")]
+    #[track_caller]
     pub fn push_id_int(&self, id: i32) -> IdStackToken<'_> {
+        self.assert_in_frame("Ui::push_id_int");
         unsafe { sys::igPushID_Int(id) }
         IdStackToken::new(self)
     }
@@ -504,8 +715,182 @@ impl<'ui> Ui {
     ///
     /// [push_id]: Self::push_id
     #[doc(alias = "PushId")]
+    #[track_caller]
     pub fn push_id_ptr<T>(&self, value: &T) -> IdStackToken<'_> {
+        self.assert_in_frame("Ui::push_id_ptr");
         unsafe { sys::igPushID_Ptr(value as *const T as *const _) }
         IdStackToken::new(self)
     }
 }
+
+#[test]
+fn test_push_font_changes_font_size() {
+    use crate::{FontConfig, FontSource};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let large_font = ctx.fonts().add_font(&[FontSource::DefaultFontData {
+        config: Some(FontConfig {
+            size_pixels: 26.0,
+            ..FontConfig::default()
+        }),
+    }]);
+    ctx.fonts().build_rgba32_texture();
+    let ui = ctx.new_frame();
+    ui.window("Fonts").build(|| {
+        let default_size = ui.current_font_size();
+        let token = ui.push_font(large_font);
+        assert_eq!(ui.current_font_size(), 26.0);
+        assert_ne!(ui.current_font_size(), default_size);
+        token.pop();
+        assert_eq!(ui.current_font_size(), default_size);
+    });
+}
+
+#[test]
+fn test_push_button_repeat_fires_while_held() {
+    use crate::test::{click_item, item_rect};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.io_mut().key_repeat_delay = 0.1;
+    ctx.io_mut().key_repeat_rate = 0.05;
+    ctx.io_mut().delta_time = 0.05;
+    let mut presses = 0;
+    // Two frames to lay out the button, then hold it down for a while
+    let mouse_down: Vec<_> = (0..12).map(|frame| frame >= 2).collect();
+    click_item(&mut ctx, &mouse_down, |ui| {
+        let _repeat = ui.push_button_repeat(true);
+        if ui.button("+") {
+            presses += 1;
+        }
+        item_rect(ui)
+    });
+    assert!(presses > 2, "only {} presses", presses);
+}
+
+#[test]
+fn test_push_clip_rect_culls_items() {
+    fn outside_item_state(clip: bool) -> (bool, bool) {
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        let mut state = (false, false);
+        crate::test::click_item(&mut ctx, &[false; 3], |ui| {
+            let top = ui.cursor_screen_pos();
+            let _clip = clip.then(|| ui.push_clip_rect(top, [200.0, top[1] + 30.0], true));
+            ui.button("Inside");
+            assert!(ui.is_item_visible());
+            ui.dummy([10.0, 50.0]);
+            ui.button("Outside");
+            state = (ui.is_item_visible(), ui.is_item_hovered());
+            crate::test::item_rect(ui)
+        });
+        state
+    }
+    assert_eq!(outside_item_state(false), (true, true));
+    assert_eq!(outside_item_state(true), (false, false));
+}
+
+#[test]
+fn test_calc_item_width_follows_item_width_stack() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    ui.window("Widths")
+        .size([300.0, 100.0], crate::Condition::Always)
+        .build(|| {
+            let avail = ui.content_region_avail()[0];
+            let default = ui.calc_item_width();
+            assert!(default > 0.0 && default < avail);
+
+            let token = ui.push_item_width(120.0);
+            assert_eq!(ui.calc_item_width(), 120.0);
+            {
+                let _inner = ui.push_item_width(-20.0);
+                assert_eq!(ui.calc_item_width(), avail - 20.0);
+            }
+            assert_eq!(ui.calc_item_width(), 120.0);
+            token.end();
+            assert_eq!(ui.calc_item_width(), default);
+        });
+}
+
+#[test]
+fn test_push_style_colors_pops_all() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    let text = ui.style_color(StyleColor::Text);
+    let button = ui.style_color(StyleColor::Button);
+    const RED: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+    const GREEN: [f32; 4] = [0.0, 1.0, 0.0, 1.0];
+    const BLUE: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
+
+    let outer = ui.push_style_color(StyleColor::Text, GREEN);
+    let token = ui.push_style_colors(&[
+        (StyleColor::Text, RED),
+        (StyleColor::Button, GREEN),
+        (StyleColor::Text, BLUE),
+    ]);
+    assert_eq!(token.count(), 3);
+    assert_eq!(ui.style_color(StyleColor::Text), BLUE);
+    assert_eq!(ui.style_color(StyleColor::Button), GREEN);
+    token.pop();
+    // Exactly three entries were popped, so the outer push is still in effect
+    assert_eq!(ui.style_color(StyleColor::Text), GREEN);
+    assert_eq!(ui.style_color(StyleColor::Button), button);
+    outer.pop();
+    assert_eq!(ui.style_color(StyleColor::Text), text);
+
+    let empty = ui.push_style_colors(&[]);
+    assert_eq!(empty.count(), 0);
+    empty.end();
+    assert_eq!(ui.style_color(StyleColor::Text), text);
+}
+
+#[cfg(feature = "docking")]
+#[test]
+fn test_push_item_flags() {
+    fn current_flags() -> u32 {
+        unsafe { (*sys::igGetCurrentContext()).CurrentItemFlags as u32 }
+    }
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    let base = current_flags();
+    assert_eq!(base & ItemFlags::all().bits(), 0);
+    let token = ui.push_item_flags(ItemFlags::NO_TAB_STOP | ItemFlags::DISABLED, true);
+    assert_eq!(
+        current_flags() & ItemFlags::all().bits(),
+        (ItemFlags::NO_TAB_STOP | ItemFlags::DISABLED).bits()
+    );
+    {
+        let _inner = ui.push_item_flags(ItemFlags::DISABLED, false);
+        assert_eq!(
+            current_flags() & ItemFlags::all().bits(),
+            ItemFlags::NO_TAB_STOP.bits()
+        );
+    }
+    assert!(current_flags() & ItemFlags::DISABLED.bits() != 0);
+    token.end();
+    assert_eq!(current_flags(), base);
+}
+
+#[test]
+fn test_push_text_wrap_pos_wraps_text() {
+    const HELP: &str = "Some long help text that doesn't fit into a narrow panel at all";
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    ui.window("Help")
+        .size([600.0, 400.0], crate::Condition::Always)
+        .build(|| {
+            ui.text(HELP);
+            let unwrapped = ui.item_rect_size();
+
+            let wrap = ui.push_text_wrap_pos_with_pos(ui.cursor_pos()[0] + 100.0);
+            ui.text(HELP);
+            let wrapped = ui.item_rect_size();
+            wrap.end();
+            assert!(wrapped[1] > unwrapped[1] * 2.0);
+            assert!(wrapped[0] <= 100.0);
+
+            // Wrapping at the window edge leaves text that fits alone
+            let _wrap = ui.push_text_wrap_pos();
+            ui.text(HELP);
+            assert_eq!(ui.item_rect_size(), unwrapped);
+        });
+}
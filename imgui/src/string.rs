@@ -1,8 +1,9 @@
 use std::borrow::{Borrow, Cow};
+use std::convert::TryFrom;
 use std::ffi::CStr;
 use std::ops::{Deref, Index, RangeFull};
 use std::os::raw::c_char;
-use std::str;
+use std::str::{self, FromStr};
 use std::{fmt, ptr};
 
 /// this is the unsafe cell upon which we build our abstraction.
@@ -42,6 +43,10 @@ impl UiBuffer {
         txt_1: impl AsRef<str>,
     ) -> (*const sys::cty::c_char, *const sys::cty::c_char) {
         self.refresh_buffer();
+        let (txt_0, txt_1) = (txt_0.as_ref(), txt_1.as_ref());
+        // Reserve up front, so pushing the second string can't reallocate (and invalidate the
+        // pointer to) the first one
+        self.buffer.reserve(txt_0.len() + txt_1.len() + 2);
         (self.push(txt_0), self.push(txt_1))
     }
 
@@ -76,12 +81,69 @@ impl UiBuffer {
     }
 }
 
+/// Bump allocator for nul-terminated strings which live until the end of the frame.
+///
+/// Unlike [`UiBuffer`], strings are never moved once written: when the current chunk is full a
+/// new one is allocated, so earlier strings stay valid until [`reset`](Self::reset).
+#[derive(Debug, Default)]
+pub struct FrameArena {
+    chunks: Vec<Vec<u8>>,
+}
+
+impl FrameArena {
+    const MIN_CHUNK_LEN: usize = 1024;
+
+    /// Copies `txt` into the arena, truncated at its first nul byte (if any), and returns a
+    /// pointer to the nul-terminated copy.
+    pub fn push(&mut self, txt: &str) -> *const u8 {
+        let txt = txt.split('\0').next().unwrap_or_default();
+        let needed = txt.len() + 1;
+        let fits = match self.chunks.last() {
+            Some(chunk) => chunk.capacity() - chunk.len() >= needed,
+            None => false,
+        };
+        if !fits {
+            let last_capacity = self.chunks.last().map_or(0, |chunk| chunk.capacity());
+            let capacity = needed.max(last_capacity * 2).max(Self::MIN_CHUNK_LEN);
+            self.chunks.push(Vec::with_capacity(capacity));
+        }
+        let chunk = self.chunks.last_mut().unwrap();
+        let start = chunk.len();
+        // Never grows past the capacity, so earlier strings in the chunk don't move
+        chunk.extend_from_slice(txt.as_bytes());
+        chunk.push(b'\0');
+        unsafe { chunk.as_ptr().add(start) }
+    }
+
+    /// Returns the number of bytes in use, including nul terminators.
+    pub fn len(&self) -> usize {
+        self.chunks.iter().map(Vec::len).sum()
+    }
+
+    /// Returns true if no strings are allocated.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Frees all strings, keeping the largest chunk around for the next frame.
+    pub fn reset(&mut self) {
+        if let Some(mut chunk) = self.chunks.pop() {
+            chunk.clear();
+            self.chunks.clear();
+            self.chunks.push(chunk);
+        }
+    }
+}
+
 /// A UTF-8 encoded, growable, implicitly nul-terminated string.
 #[derive(Clone, Hash, Ord, Eq, PartialOrd, PartialEq)]
 pub struct ImString(pub(crate) Vec<u8>);
 
 impl ImString {
     /// Creates a new `ImString` from an existing string.
+    ///
+    /// The string is silently truncated at the first interior nul byte, if any. Use
+    /// [`try_new`](Self::try_new) to treat this as an error instead.
     pub fn new<T: Into<String>>(value: T) -> ImString {
         unsafe {
             let mut s = ImString::from_utf8_unchecked(value.into().into_bytes());
@@ -90,6 +152,21 @@ impl ImString {
         }
     }
 
+    /// Creates a new `ImString` from an existing string, failing if it contains a nul byte.
+    pub fn try_new<T: Into<String>>(value: T) -> Result<ImString, ImStringError> {
+        let value = value.into();
+        match value.bytes().position(|b| b == b'\0') {
+            Some(position) => Err(ImStringError::InteriorNul { position }),
+            None => Ok(unsafe { ImString::from_utf8_unchecked(value.into_bytes()) }),
+        }
+    }
+
+    /// Converts a vector of bytes to an `ImString`, failing if the bytes are not valid UTF-8 or
+    /// contain a nul byte.
+    pub fn from_utf8(v: Vec<u8>) -> Result<ImString, ImStringError> {
+        ImString::try_new(String::from_utf8(v)?)
+    }
+
     /// Creates a new empty `ImString` with a particular capacity
     #[inline]
     pub fn with_capacity(capacity: usize) -> ImString {
@@ -211,6 +288,10 @@ impl<'a> Default for ImString {
     }
 }
 
+/// Truncates the string at the first interior nul byte, like [`ImString::new`].
+///
+/// Because of this impl, `ImString::try_from(String)` can't fail; use [`ImString::try_new`] to
+/// reject interior nul bytes while reusing the string's allocation.
 impl From<String> for ImString {
     #[inline]
     fn from(s: String) -> ImString {
@@ -218,6 +299,24 @@ impl From<String> for ImString {
     }
 }
 
+impl<'a> TryFrom<&'a str> for ImString {
+    type Error = ImStringError;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<ImString, ImStringError> {
+        ImString::try_new(s)
+    }
+}
+
+impl FromStr for ImString {
+    type Err = ImStringError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<ImString, ImStringError> {
+        ImString::try_new(s)
+    }
+}
+
 impl<'a> From<ImString> for Cow<'a, ImStr> {
     #[inline]
     fn from(s: ImString) -> Cow<'a, ImStr> {
@@ -316,7 +415,60 @@ impl fmt::Write for ImString {
     }
 }
 
+/// Error returned when converting to an [`ImString`] fails
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ImStringError {
+    /// The string contains a nul byte at the given byte offset
+    InteriorNul { position: usize },
+    /// The bytes are not valid UTF-8
+    NotUtf8,
+    /// The bytes don't end with a nul terminator
+    NotNulTerminated,
+}
+
+impl fmt::Display for ImStringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImStringError::InteriorNul { position } => {
+                write!(f, "String contains a nul byte at position {}", position)
+            }
+            ImStringError::NotUtf8 => f.pad("String is not valid UTF-8"),
+            ImStringError::NotNulTerminated => f.pad("String is not nul-terminated"),
+        }
+    }
+}
+
+impl std::error::Error for ImStringError {}
+
+impl From<std::ffi::NulError> for ImStringError {
+    fn from(err: std::ffi::NulError) -> Self {
+        ImStringError::InteriorNul {
+            position: err.nul_position(),
+        }
+    }
+}
+
+impl From<str::Utf8Error> for ImStringError {
+    fn from(_: str::Utf8Error) -> Self {
+        ImStringError::NotUtf8
+    }
+}
+
+impl From<std::string::FromUtf8Error> for ImStringError {
+    fn from(_: std::string::FromUtf8Error) -> Self {
+        ImStringError::NotUtf8
+    }
+}
+
 /// A UTF-8 encoded, implicitly nul-terminated string slice.
+///
+/// Widgets don't need this: they take `impl AsRef<str>` and copy the text (plus a nul
+/// terminator) into the [`Ui`](crate::Ui) scratch buffer, so string literals can be passed
+/// directly. This is also why the `im_str!` macro was removed rather than made `const`:
+/// an `ImStr` built at compile time would be copied all the same.
+///
+/// Passing an `ImStr` or [`ImString`] label is copied too: through `AsRef<str>` a widget only
+/// sees the text, not the nul terminator that follows it.
 #[derive(Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct ImStr([u8]);
@@ -339,7 +491,31 @@ impl fmt::Debug for ImStr {
 impl fmt::Display for ImStr {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(self.to_str(), f)
+        // Unlike `to_str`, stay safe if unchecked constructors were given invalid UTF-8
+        let bytes = self.0.split_last().map_or(&[][..], |(_, bytes)| bytes);
+        fmt::Display::fmt(&String::from_utf8_lossy(bytes), f)
+    }
+}
+
+impl Deref for ImStr {
+    type Target = str;
+    #[inline]
+    fn deref(&self) -> &str {
+        self.to_str()
+    }
+}
+
+impl PartialEq<str> for ImStr {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.to_str() == other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for ImStr {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.to_str() == *other
     }
 }
 
@@ -355,6 +531,34 @@ impl ImStr {
         ImStr::from_cstr_unchecked(CStr::from_ptr(ptr))
     }
 
+    /// Converts a nul-terminated slice of bytes to an imgui-rs string slice, without copying.
+    ///
+    /// Like [`CStr::from_bytes_with_nul`], the slice must end with the only nul byte it
+    /// contains. The contents must also be valid UTF-8.
+    pub fn from_bytes_with_nul(bytes: &[u8]) -> Result<&ImStr, ImStringError> {
+        let text = match bytes.split_last() {
+            Some((0, text)) => text,
+            _ => return Err(ImStringError::NotNulTerminated),
+        };
+        if let Some(position) = text.iter().position(|&b| b == 0) {
+            return Err(ImStringError::InteriorNul { position });
+        }
+        str::from_utf8(text)?;
+        Ok(unsafe { ImStr::from_utf8_with_nul_unchecked(bytes) })
+    }
+
+    /// Converts a slice of bytes to an imgui-rs string slice without any checks, like
+    /// [`CStr::from_bytes_with_nul_unchecked`].
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to guarantee the slice contains valid UTF-8 and ends with its only
+    /// nul byte.
+    #[inline]
+    pub unsafe fn from_bytes_with_nul_unchecked(bytes: &[u8]) -> &ImStr {
+        ImStr::from_utf8_with_nul_unchecked(bytes)
+    }
+
     /// Converts a slice of bytes to an imgui-rs string slice without checking for valid UTF-8 or
     /// null termination.
     ///
@@ -514,3 +718,126 @@ fn test_imstring_interior_nul() {
     assert_eq!(s.to_str(), "");
     assert!(s.is_empty());
 }
+
+#[test]
+fn test_imstring_try_new() {
+    assert_eq!(
+        ImString::try_new("test").map(|s| s.0),
+        Ok(b"test\0".to_vec())
+    );
+    assert_eq!(
+        ImString::try_new("test\0ohno"),
+        Err(ImStringError::InteriorNul { position: 4 })
+    );
+    assert_eq!(
+        ImString::from_utf8("ä\0".as_bytes().to_vec()),
+        Err(ImStringError::InteriorNul { position: 2 })
+    );
+    assert_eq!(
+        ImString::from_utf8(vec![b'a', 0xff]),
+        Err(ImStringError::NotUtf8)
+    );
+
+    // Errors from the standard library conversions convert with `?`
+    fn c_string(value: &str) -> Result<std::ffi::CString, ImStringError> {
+        Ok(std::ffi::CString::new(value)?)
+    }
+    assert_eq!(
+        c_string("ab\0c"),
+        Err(ImStringError::InteriorNul { position: 2 })
+    );
+    assert_eq!(
+        ImStringError::InteriorNul { position: 2 }.to_string(),
+        "String contains a nul byte at position 2"
+    );
+}
+
+#[test]
+fn test_imstring_conversion_traits() {
+    let s: ImString = "label".parse().unwrap();
+    assert_eq!(s.0, b"label\0");
+    assert_eq!(
+        "lab\0el".parse::<ImString>(),
+        Err(ImStringError::InteriorNul { position: 3 })
+    );
+    assert_eq!(ImString::try_from("label"), Ok(s));
+    assert_eq!(
+        ImString::try_from("\0label"),
+        Err(ImStringError::InteriorNul { position: 0 })
+    );
+
+    // The owned string's buffer is reused when there's room for the nul terminator
+    let mut owned = String::with_capacity(16);
+    owned.push_str("label");
+    let ptr = owned.as_ptr();
+    let s = ImString::try_new(owned).unwrap();
+    assert_eq!(s.as_ptr() as *const u8, ptr);
+}
+
+#[test]
+fn test_imstr_str_ergonomics() {
+    let owned = ImString::new("label");
+    let s: &ImStr = &owned;
+    assert_eq!(format!("{}", s), "label");
+    assert_eq!(format!("[{:>7}]", s), "[  label]");
+    assert_eq!(format!("{}", <&ImStr>::default()), "");
+    assert!(*s == *"label");
+    assert!(*s == "label");
+    // Deref gives access to all of `str`'s methods
+    assert!(s.starts_with("lab"));
+    assert_eq!(s.len(), 5);
+    fn takes_str(s: &str) -> usize {
+        s.chars().count()
+    }
+    assert_eq!(takes_str(s), 5);
+    assert_eq!(takes_str(&owned), 5);
+
+    let invalid = unsafe { ImStr::from_utf8_with_nul_unchecked(b"a\xffb\0") };
+    assert_eq!(format!("{}", invalid), "a\u{fffd}b");
+}
+
+#[test]
+fn test_imstr_from_bytes_with_nul() {
+    let bytes = b"label\0";
+    let s = ImStr::from_bytes_with_nul(bytes).unwrap();
+    assert_eq!(s.as_ptr() as *const u8, bytes.as_ptr());
+    assert_eq!(s.to_str(), "label");
+    assert_eq!(
+        ImStr::from_bytes_with_nul(b"\0").map(|s| s.is_empty()),
+        Ok(true)
+    );
+
+    assert_eq!(
+        ImStr::from_bytes_with_nul(b"label"),
+        Err(ImStringError::NotNulTerminated)
+    );
+    assert_eq!(
+        ImStr::from_bytes_with_nul(b""),
+        Err(ImStringError::NotNulTerminated)
+    );
+    assert_eq!(
+        ImStr::from_bytes_with_nul(b"la\0bel\0"),
+        Err(ImStringError::InteriorNul { position: 2 })
+    );
+    assert_eq!(
+        ImStr::from_bytes_with_nul(b"label\0\0"),
+        Err(ImStringError::InteriorNul { position: 5 })
+    );
+    assert_eq!(
+        ImStr::from_bytes_with_nul(b"\xfflabel\0"),
+        Err(ImStringError::NotUtf8)
+    );
+    let s = unsafe { ImStr::from_bytes_with_nul_unchecked(bytes) };
+    assert_eq!(s.to_str(), "label");
+}
+
+#[test]
+fn test_ui_buffer_scratch_txt_two() {
+    let mut buffer = UiBuffer::new(1024);
+    let long = "x".repeat(100);
+    let (one, two) = buffer.scratch_txt_two("label", &long);
+    unsafe {
+        assert_eq!(CStr::from_ptr(one).to_bytes(), b"label");
+        assert_eq!(CStr::from_ptr(two).to_bytes(), long.as_bytes());
+    }
+}
@@ -7,6 +7,11 @@ use crate::sys;
 use crate::Direction;
 
 /// User interface style/colors
+///
+/// The live style of a context is accessed through [`Context::style_mut`](crate::Context::style_mut).
+/// `Style` is also a plain `Copy` value with the same layout as `ImGuiStyle`, so copies made with
+/// [`Context::clone_style`](crate::Context::clone_style) hold every field and can be applied with
+/// [`Context::set_style`](crate::Context::set_style), e.g. to switch between themes.
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Style {
@@ -25,15 +30,18 @@ pub struct Style {
     ///
     /// Generally set to 0.0 or 1.0 (other values are not well tested and cost more CPU/GPU).
     pub window_border_size: f32,
-    /// Minimum window size
+    /// Minimum window size.
+    ///
+    /// This is a global setting: windows can't be made smaller than this, even with an
+    /// explicit size.
     pub window_min_size: [f32; 2],
     /// Alignment for title bar text.
     ///
-    /// Defaults to [0.5, 0.5] for left-aligned, vertically centered.
+    /// Defaults to [0.0, 0.5] for left-aligned, vertically centered.
     pub window_title_align: [f32; 2],
     /// Side of the collapsing/docking button in the title bar (left/right).
     ///
-    /// Defaults to Direction::Left.
+    /// Defaults to Direction::Left. Use Direction::None to hide the button.
     pub window_menu_button_position: Direction,
     /// Rounding radius of child window corners.
     ///
@@ -103,7 +111,7 @@ pub struct Style {
     /// `= 0.0`: always show when hovering
     /// `= f32::MAX`: never show close button unless selected
     pub tab_min_width_for_close_button: f32,
-    /// Side of the color buttonton pubin color editor widgets (left/right).
+    /// Side of the color button in color editor widgets (left/right).
     pub color_button_position: Direction,
     /// Alignment of button text when button is larger than text.
     ///
@@ -111,7 +119,7 @@ pub struct Style {
     pub button_text_align: [f32; 2],
     /// Alignment of selectable text when selectable is larger than text.
     ///
-    /// Defaults to [0.5, 0.5] (top-left aligned).
+    /// Defaults to [0.0, 0.0] (top-left aligned).
     pub selectable_text_align: [f32; 2],
     /// Window positions are clamped to be visible within the display area or monitors by at least
     /// this amount.
@@ -120,7 +128,8 @@ pub struct Style {
     pub display_window_padding: [f32; 2],
     /// If you cannot see the edges of your screen (e.g. on a TV), increase the safe area padding.
     ///
-    /// Also applies to popups/tooltips in addition to regular windows.
+    /// Also applies to popups/tooltips in addition to regular windows, and pushes the contents
+    /// of the main menu bar away from the screen edges.
     pub display_safe_area_padding: [f32; 2],
     /// Scale software-rendered mouse cursor.
     ///
@@ -129,25 +138,32 @@ pub struct Style {
     /// Enable anti-aliased lines/borders.
     ///
     /// Disable if you are really tight on CPU/GPU. Latched at the beginning of the frame.
+    /// Anti-aliased lines are drawn with extra fringe vertices, so disabling this makes lines
+    /// cheaper but jagged.
     pub anti_aliased_lines: bool,
     /// Enable anti-aliased lines/borders using textures where possible.
     ///
     /// Require back-end to render with bilinear filtering. Latched at the beginning of the frame.
+    /// Thin lines then use a single textured quad per segment, which is much cheaper than
+    /// geometry based anti-aliasing.
     pub anti_aliased_lines_use_tex: bool,
-    /// Enable anti-aliased edges around filled shapes (rounded recatngles, circles, etc.).
+    /// Enable anti-aliased edges around filled shapes (rounded rectangles, circles, etc.).
     ///
     /// Disable if you are really tight on CPU/GPU. Latched at the beginning of the frame.
+    /// The anti-aliased edge is a thin strip of extra triangles around each shape, which costs
+    /// vertices and fill rate.
     pub anti_aliased_fill: bool,
     /// Tessellation tolerance when using path_bezier_curve_to without a specific number of
     /// segments.
     ///
     /// Decrease for highly tessellated curves (higher quality, more polygons), increase to reduce
-    /// quality.
+    /// quality. Defaults to 1.25.
     pub curve_tessellation_tol: f32,
     /// Maximum error (in pixels) allowed when drawing circles or rounded corner rectangles with no
     /// explicit segment count specified.
     ///
-    /// Decrease for higher quality but more geometry.
+    /// Decrease for higher quality but more geometry. Defaults to 0.30; small circles are
+    /// drawn with few segments, so lowering this makes them look rounder.
     pub circle_tesselation_max_error: f32,
     /// Style colors.
     pub colors: [[f32; 4]; StyleColor::COUNT],
@@ -163,7 +179,96 @@ impl Style {
             sys::ImGuiStyle_ScaleAllSizes(self.raw_mut(), scale_factor);
         }
     }
-    /// Replaces current colors with classic Dear ImGui style
+    /// Interpolates between this style (`t = 0.0`) and `other` (`t = 1.0`), e.g. to animate a
+    /// theme change over a few frames by assigning the result to the context style each frame.
+    ///
+    /// Sizes are interpolated linearly. Colors are interpolated in linear light rather than in
+    /// sRGB, which avoids the muddy, too dark midpoints a naive blend would give; alpha is
+    /// interpolated as is. Flags and directions switch over at `t = 0.5`. `t` is clamped to
+    /// `0.0..=1.0`, and the endpoints return exact copies of the inputs.
+    pub fn lerp(&self, other: &Style, t: f32) -> Style {
+        if t <= 0.0 {
+            return *self;
+        }
+        if t >= 1.0 {
+            return *other;
+        }
+        let scalar = |a: f32, b: f32| a * (1.0 - t) + b * t;
+        let vec2 = |a: [f32; 2], b: [f32; 2]| [scalar(a[0], b[0]), scalar(a[1], b[1])];
+        let to_linear = |c: f32| {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let to_srgb = |c: f32| {
+            if c <= 0.003_130_8 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        };
+        let color = |a: [f32; 4], b: [f32; 4]| {
+            let channel = |i: usize| to_srgb(scalar(to_linear(a[i]), to_linear(b[i])));
+            [channel(0), channel(1), channel(2), scalar(a[3], b[3])]
+        };
+
+        let mut style = if t < 0.5 { *self } else { *other };
+        style.alpha = scalar(self.alpha, other.alpha);
+        style.disabled_alpha = scalar(self.disabled_alpha, other.disabled_alpha);
+        style.window_rounding = scalar(self.window_rounding, other.window_rounding);
+        style.window_border_size = scalar(self.window_border_size, other.window_border_size);
+        style.child_rounding = scalar(self.child_rounding, other.child_rounding);
+        style.child_border_size = scalar(self.child_border_size, other.child_border_size);
+        style.popup_rounding = scalar(self.popup_rounding, other.popup_rounding);
+        style.popup_border_size = scalar(self.popup_border_size, other.popup_border_size);
+        style.frame_rounding = scalar(self.frame_rounding, other.frame_rounding);
+        style.frame_border_size = scalar(self.frame_border_size, other.frame_border_size);
+        style.indent_spacing = scalar(self.indent_spacing, other.indent_spacing);
+        style.columns_min_spacing = scalar(self.columns_min_spacing, other.columns_min_spacing);
+        style.scrollbar_size = scalar(self.scrollbar_size, other.scrollbar_size);
+        style.scrollbar_rounding = scalar(self.scrollbar_rounding, other.scrollbar_rounding);
+        style.grab_min_size = scalar(self.grab_min_size, other.grab_min_size);
+        style.grab_rounding = scalar(self.grab_rounding, other.grab_rounding);
+        style.log_slider_deadzone = scalar(self.log_slider_deadzone, other.log_slider_deadzone);
+        style.tab_rounding = scalar(self.tab_rounding, other.tab_rounding);
+        style.tab_border_size = scalar(self.tab_border_size, other.tab_border_size);
+        style.tab_min_width_for_close_button = scalar(
+            self.tab_min_width_for_close_button,
+            other.tab_min_width_for_close_button,
+        );
+        style.mouse_cursor_scale = scalar(self.mouse_cursor_scale, other.mouse_cursor_scale);
+        style.curve_tessellation_tol =
+            scalar(self.curve_tessellation_tol, other.curve_tessellation_tol);
+        style.circle_tesselation_max_error = scalar(
+            self.circle_tesselation_max_error,
+            other.circle_tesselation_max_error,
+        );
+        style.window_padding = vec2(self.window_padding, other.window_padding);
+        style.window_min_size = vec2(self.window_min_size, other.window_min_size);
+        style.window_title_align = vec2(self.window_title_align, other.window_title_align);
+        style.frame_padding = vec2(self.frame_padding, other.frame_padding);
+        style.item_spacing = vec2(self.item_spacing, other.item_spacing);
+        style.item_inner_spacing = vec2(self.item_inner_spacing, other.item_inner_spacing);
+        style.cell_padding = vec2(self.cell_padding, other.cell_padding);
+        style.touch_extra_padding = vec2(self.touch_extra_padding, other.touch_extra_padding);
+        style.button_text_align = vec2(self.button_text_align, other.button_text_align);
+        style.selectable_text_align = vec2(self.selectable_text_align, other.selectable_text_align);
+        style.display_window_padding =
+            vec2(self.display_window_padding, other.display_window_padding);
+        style.display_safe_area_padding = vec2(
+            self.display_safe_area_padding,
+            other.display_safe_area_padding,
+        );
+        for (i, out) in style.colors.iter_mut().enumerate() {
+            *out = color(self.colors[i], other.colors[i]);
+        }
+        style
+    }
+    /// Replaces current colors with classic Dear ImGui style.
+    ///
+    /// Like the other presets, this only changes the colors: sizes are kept as they are.
     #[doc(alias = "StyleColors", alias = "StlyeColorsClassic")]
     pub fn use_classic_colors(&mut self) -> &mut Self {
         unsafe {
@@ -397,10 +502,29 @@ impl StyleColor {
     /// Total count of `StyleColor` variants
     pub const COUNT: usize = sys::ImGuiCol_COUNT as usize;
 
-    /// Returns the name of the Style Color.
+    /// Returns an iterator over all `StyleColor` variants, in index order.
+    ///
+    /// Useful for theme editors, which then pick up new colors automatically.
+    pub fn iter() -> impl Iterator<Item = StyleColor> + Clone {
+        Self::VARIANTS.iter().copied()
+    }
+
+    /// Returns the name of the Style Color, as used by Dear ImGui.
+    ///
+    /// Together with [`iter`](Self::iter), this can be used to build a theme editor:
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// fn theme_editor(ui: &Ui, theme: &mut Style) {
+    ///     for color in StyleColor::iter() {
+    ///         ui.color_edit4(color.name(), &mut theme[color]);
+    ///     }
+    /// }
+    /// ```
     // Note: we do this in Rust (where we have better promises of enums
     // being of the right type) than in C++ to avoid the FFI. We confirm in
     // Unit Tests that we are accurate.
+    #[doc(alias = "GetStyleColorName")]
     pub fn name(&self) -> &'static str {
         match self {
             StyleColor::Text => "Text",
@@ -605,6 +729,116 @@ fn test_style_scaling() {
     assert_eq!(style.cell_padding, [58.0, 60.0]);
 }
 
+#[test]
+fn test_display_safe_area_padding_moves_main_menu_bar_contents() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut menu_pos = Vec::new();
+    for padding in [[0.0, 0.0], [20.0, 30.0]] {
+        ctx.style_mut().display_safe_area_padding = padding;
+        let ui = ctx.new_frame();
+        ui.main_menu_bar(|| {
+            ui.menu("File", || {});
+            menu_pos.push(ui.item_rect_min());
+        });
+        let _ = ctx.render();
+    }
+    assert!(menu_pos[1][0] > menu_pos[0][0], "{:?}", menu_pos);
+    assert!(menu_pos[1][1] > menu_pos[0][1], "{:?}", menu_pos);
+}
+
+#[test]
+fn test_style_anti_aliasing_and_tessellation() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut vertices = Vec::new();
+    for (anti_aliased_fill, max_error) in [(true, 0.3), (false, 0.3), (false, 0.1)] {
+        let style = ctx.style_mut();
+        style.anti_aliased_fill = anti_aliased_fill;
+        style.circle_tesselation_max_error = max_error;
+        let ui = ctx.new_frame();
+        ui.get_background_draw_list()
+            .add_circle([100.0, 100.0], 10.0, [1.0, 1.0, 1.0])
+            .filled(true)
+            .build();
+        vertices.push(ctx.render().total_vtx_count);
+    }
+    // No anti-aliased fringe
+    assert!(vertices[1] < vertices[0], "{:?}", vertices);
+    // More segments for the same circle
+    assert!(vertices[2] > vertices[1], "{:?}", vertices);
+}
+
+#[test]
+fn test_style_window_layout_fields() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let style = ctx.style_mut();
+    assert_eq!(style.window_menu_button_position, Direction::Left);
+    assert_eq!(style.color_button_position, Direction::Right);
+    style.window_min_size = [100.0, 80.0];
+    style.window_title_align = [0.5, 0.5];
+    style.window_menu_button_position = Direction::Right;
+    style.color_button_position = Direction::Left;
+
+    let raw = unsafe { &*sys::igGetStyle() };
+    assert_eq!(raw.WindowMinSize, sys::ImVec2::new(100.0, 80.0));
+    assert_eq!(raw.WindowTitleAlign, sys::ImVec2::new(0.5, 0.5));
+    assert_eq!(raw.WindowMenuButtonPosition, sys::ImGuiDir_Right);
+    assert_eq!(raw.ColorButtonPosition, sys::ImGuiDir_Left);
+
+    let ui = ctx.new_frame();
+    ui.window("Small")
+        .size([10.0, 10.0], crate::Condition::Always)
+        .build(|| assert_eq!(ui.window_size(), [100.0, 80.0]));
+}
+
+#[test]
+fn test_style_color_presets() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let style = ctx.style_mut();
+    let padding = style.window_padding;
+    let dark = style.use_dark_colors()[StyleColor::WindowBg];
+    let light = style.use_light_colors()[StyleColor::WindowBg];
+    let classic = style.use_classic_colors()[StyleColor::WindowBg];
+    // Light backgrounds are brighter
+    assert!(light[0] > dark[0] && light[1] > dark[1] && light[2] > dark[2]);
+    assert_ne!(classic, dark);
+    assert_eq!(style.use_dark_colors()[StyleColor::WindowBg], dark);
+    // Only colors are replaced
+    assert_eq!(style.window_padding, padding);
+}
+
+#[test]
+fn test_style_lerp() {
+    let (_guard, ctx) = crate::test::test_ctx();
+    let mut dark = *ctx.style();
+    dark.use_dark_colors();
+    dark.window_padding = [4.0, 4.0];
+    let mut light = dark;
+    light.use_light_colors();
+    light.window_padding = [12.0, 8.0];
+    light.window_menu_button_position = Direction::Right;
+
+    let start = dark.lerp(&light, 0.0);
+    let end = dark.lerp(&light, 1.0);
+    assert_eq!(start.colors, dark.colors);
+    assert_eq!(start.window_padding, dark.window_padding);
+    assert_eq!(end.colors, light.colors);
+    assert_eq!(end.window_padding, light.window_padding);
+    assert_eq!(end.window_menu_button_position, Direction::Right);
+
+    let mid = dark.lerp(&light, 0.5);
+    assert_eq!(mid.window_padding, [8.0, 6.0]);
+    assert_eq!(mid.window_menu_button_position, Direction::Right);
+    let dark_bg = dark[StyleColor::WindowBg];
+    let light_bg = light[StyleColor::WindowBg];
+    let mid_bg = mid[StyleColor::WindowBg];
+    for i in 0..3 {
+        // Blending in linear light is brighter than the sRGB average
+        assert!(mid_bg[i] > (dark_bg[i] + light_bg[i]) / 2.0);
+        assert!(mid_bg[i] < light_bg[i]);
+    }
+    assert_eq!(mid_bg[3], (dark_bg[3] + light_bg[3]) / 2.0);
+}
+
 #[test]
 fn test_style_color_indexing() {
     let (_guard, mut ctx) = crate::test::test_ctx();
@@ -680,6 +914,15 @@ fn test_style_color_variants() {
     }
 }
 
+#[test]
+fn test_style_color_iter_covers_all_colors() {
+    assert_eq!(StyleColor::iter().count(), sys::ImGuiCol_COUNT as usize);
+    for (idx, color) in StyleColor::iter().enumerate() {
+        assert_eq!(color as usize, idx);
+        assert_eq!(StyleColor::try_from(idx).unwrap(), color);
+    }
+}
+
 #[test]
 fn test_style_color_variant_names() {
     for idx in StyleColor::VARIANTS.iter() {
@@ -692,3 +935,39 @@ fn test_style_color_variant_names() {
         assert_eq!(our_name, their_name);
     }
 }
+
+#[test]
+fn test_style_color_known_names() {
+    assert_eq!(StyleColor::Text.name(), "Text");
+    assert_eq!(StyleColor::WindowBg.name(), "WindowBg");
+    assert_eq!(StyleColor::ModalWindowDimBg.name(), "ModalWindowDimBg");
+    assert_eq!(StyleColor::TableRowBgAlt.to_string(), "TableRowBgAlt");
+}
+
+#[test]
+fn test_style_clone_modify_apply() {
+    let (_guard, mut ctx) = crate::test::test_ctx();
+    let original = ctx.clone_style();
+    let mut theme = original;
+    theme.use_light_colors();
+    theme.window_rounding = 7.0;
+    theme.frame_padding = [2.0, 9.0];
+    // The copy is independent from the live style
+    assert_eq!(ctx.style().window_rounding, original.window_rounding);
+
+    ctx.set_style(&theme);
+    assert_eq!(ctx.style().window_rounding, 7.0);
+    assert_eq!(ctx.style().frame_padding, [2.0, 9.0]);
+    assert_eq!(
+        ctx.style()[StyleColor::WindowBg],
+        theme[StyleColor::WindowBg]
+    );
+
+    ctx.set_style(&original);
+    assert_eq!(ctx.style().window_rounding, original.window_rounding);
+    assert_eq!(
+        ctx.style()[StyleColor::WindowBg],
+        original[StyleColor::WindowBg]
+    );
+    assert_ne!(original[StyleColor::WindowBg], theme[StyleColor::WindowBg]);
+}
@@ -259,11 +259,13 @@ impl Ui {
     /// Nb: we take `column` as a usize, but it will be converted with `as i32` to an i32.
     /// If this makes a difference to you, you are probably trying to make too many columns.
     #[must_use = "if return is dropped immediately, table is ended immediately."]
+    #[track_caller]
     pub fn begin_table(
         &self,
         str_id: impl AsRef<str>,
         column_count: usize,
     ) -> Option<TableToken<'_>> {
+        self.assert_in_frame("Ui::begin_table");
         self.begin_table_with_flags(str_id, column_count, TableFlags::empty())
     }
 
@@ -276,12 +278,14 @@ impl Ui {
     /// Nb: we take `column` as a usize, but it will be converted with `as i32` to an i32.
     /// If this makes a difference to you, you are probably trying to make too many columns.
     #[must_use = "if return is dropped immediately, table is ended immediately."]
+    #[track_caller]
     pub fn begin_table_with_flags(
         &self,
         str_id: impl AsRef<str>,
         column_count: usize,
         flags: TableFlags,
     ) -> Option<TableToken<'_>> {
+        self.assert_in_frame("Ui::begin_table_with_flags");
         self.begin_table_with_sizing(str_id, column_count, flags, [0.0, 0.0], 0.0)
     }
 
@@ -295,6 +299,7 @@ impl Ui {
     /// Nb: we take `column` as a usize, but it will be converted with `as i32` to an i32.
     /// If this makes a difference to you, you are probably trying to make too many columns.
     #[must_use = "if return is dropped immediately, table is ended immediately."]
+    #[track_caller]
     pub fn begin_table_with_sizing(
         &self,
         str_id: impl AsRef<str>,
@@ -303,6 +308,7 @@ impl Ui {
         outer_size: [f32; 2],
         inner_width: f32,
     ) -> Option<TableToken<'_>> {
+        self.assert_in_frame("Ui::begin_table_with_sizing");
         unsafe {
             sys::igBeginTable(
                 self.scratch_txt(str_id),
@@ -320,11 +326,13 @@ impl Ui {
     /// Takes an array of table header information, the length of which determines
     /// how many columns will be created.
     #[must_use = "if return is dropped immediately, table is ended immediately."]
+    #[track_caller]
     pub fn begin_table_header<Name: AsRef<str>, const N: usize>(
         &self,
         str_id: impl AsRef<str>,
         column_data: [TableColumnSetup<Name>; N],
     ) -> Option<TableToken<'_>> {
+        self.assert_in_frame("Ui::begin_table_header");
         self.begin_table_header_with_flags(str_id, column_data, TableFlags::empty())
     }
 
@@ -333,12 +341,14 @@ impl Ui {
     /// Takes an array of table header information, the length of which determines
     /// how many columns will be created.
     #[must_use = "if return is dropped immediately, table is ended immediately."]
+    #[track_caller]
     pub fn begin_table_header_with_flags<Name: AsRef<str>, const N: usize>(
         &self,
         str_id: impl AsRef<str>,
         column_data: [TableColumnSetup<Name>; N],
         flags: TableFlags,
     ) -> Option<TableToken<'_>> {
+        self.assert_in_frame("Ui::begin_table_header_with_flags");
         self.begin_table_header_with_sizing(str_id, column_data, flags, [0.0, 0.0], 0.0)
     }
 
@@ -347,6 +357,7 @@ impl Ui {
     /// Takes an array of table header information, the length of which determines
     /// how many columns will be created.
     #[must_use = "if return is dropped immediately, table is ended immediately."]
+    #[track_caller]
     pub fn begin_table_header_with_sizing<Name: AsRef<str>, const N: usize>(
         &self,
         str_id: impl AsRef<str>,
@@ -355,6 +366,7 @@ impl Ui {
         outer_size: [f32; 2],
         inner_width: f32,
     ) -> Option<TableToken<'_>> {
+        self.assert_in_frame("Ui::begin_table_header_with_sizing");
         self.begin_table_with_sizing(str_id, N, flags, outer_size, inner_width)
             .map(|data| {
                 for value in column_data {
@@ -378,7 +390,9 @@ impl Ui {
     /// [begin_table_header]: Self::begin_table_header
     /// [table_next_column]: Self::table_next_column
     #[inline]
+    #[track_caller]
     pub fn table_next_row(&self) {
+        self.assert_in_frame("Ui::table_next_row");
         self.table_next_row_with_flags(TableRowFlags::empty());
     }
 
@@ -391,7 +405,9 @@ impl Ui {
     /// See [table_next_row](Self::table_next_row) for information on how moving rows work. To set the row
     /// with a given height, see [table_next_row_with_height](Self::table_next_row_with_height).
     #[inline]
+    #[track_caller]
     pub fn table_next_row_with_flags(&self, flags: TableRowFlags) {
+        self.assert_in_frame("Ui::table_next_row_with_flags");
         self.table_next_row_with_height(flags, 0.0);
     }
 
@@ -400,7 +416,9 @@ impl Ui {
     ///
     /// See [table_next_row](Self::table_next_row) for information on how moving rows work.
     #[inline]
+    #[track_caller]
     pub fn table_next_row_with_height(&self, flags: TableRowFlags, min_row_height: f32) {
+        self.assert_in_frame("Ui::table_next_row_with_height");
         unsafe {
             sys::igTableNextRow(flags.bits() as i32, min_row_height);
         }
@@ -438,7 +456,9 @@ impl Ui {
     /// This functions returns true if the given column is **visible.** It is not
     /// marked as must use, as you can still render commands into the not-visible column,
     /// though you can choose to not as an optimization.
+    #[track_caller]
     pub fn table_next_column(&self) -> bool {
+        self.assert_in_frame("Ui::table_next_column");
         unsafe { sys::igTableNextColumn() }
     }
 
@@ -479,7 +499,9 @@ impl Ui {
     /// If `column_index >= ui.table_columm_count`, this function will panic. In `debug` releases,
     /// we will panic on the Rust side, for a nicer error message, though in release, we will
     /// panic in C++, which will result in an ugly stack overflow.
+    #[track_caller]
     pub fn table_set_column_index(&self, column_index: usize) -> bool {
+        self.assert_in_frame("Ui::table_set_column_index");
         #[cfg(debug_assertions)]
         {
             let size = self.table_column_count() as usize;
@@ -519,7 +541,9 @@ impl Ui {
     /// row and automatically submit a table header for each column.
     /// Headers are required to perform: reordering, sorting, and opening the context menu (though,
     /// the context menu can also be made available in columns body using [TableFlags::CONTEXT_MENU_IN_BODY].
+    #[track_caller]
     pub fn table_setup_column(&self, str_id: impl AsRef<str>) {
+        self.assert_in_frame("Ui::table_setup_column");
         self.table_setup_column_with(TableColumnSetup::new(str_id))
     }
 
@@ -533,7 +557,9 @@ impl Ui {
     /// row and automatically submit a table header for each column.
     /// Headers are required to perform: reordering, sorting, and opening the context menu (though,
     /// the context menu can also be made available in columns body using [TableFlags::CONTEXT_MENU_IN_BODY].
+    #[track_caller]
     pub fn table_setup_column_with<N: AsRef<str>>(&self, data: TableColumnSetup<N>) {
+        self.assert_in_frame("Ui::table_setup_column_with");
         unsafe {
             sys::igTableSetupColumn(
                 self.scratch_txt(data.name),
@@ -567,7 +593,9 @@ impl Ui {
     /// Nb: we take `locked_columns` and `locked_rows` as a `usize`, but it will be converted
     /// with `as i32` to an i32. If this makes a difference to you, you are probably
     /// trying to make too many columns.
+    #[track_caller]
     pub fn table_setup_scroll_freeze(&self, locked_columns: usize, locked_rows: usize) {
+        self.assert_in_frame("Ui::table_setup_scroll_freeze");
         unsafe {
             sys::igTableSetupScrollFreeze(locked_columns as i32, locked_rows as i32);
         }
@@ -587,7 +615,9 @@ impl Ui {
     ///
     /// [table_next_column]: Self::table_next_column
     /// [table_header]: Self::table_header
+    #[track_caller]
     pub fn table_headers_row(&self) {
+        self.assert_in_frame("Ui::table_headers_row");
         unsafe {
             sys::igTableHeadersRow();
         }
@@ -598,7 +628,9 @@ impl Ui {
     /// You generally should avoid using this outside of specific cases,
     /// such as custom widgets. Instead, use [table_headers_row](Self::table_headers_row)
     /// and [table_setup_column](Self::table_setup_column).
+    #[track_caller]
     pub fn table_header(&self, label: impl AsRef<str>) {
+        self.assert_in_frame("Ui::table_header");
         unsafe {
             sys::igTableHeader(self.scratch_txt(label));
         }
@@ -685,7 +717,9 @@ impl Ui {
     ///
     /// Use [table_set_bg_color_with_column](Self::table_set_bg_color_with_column) to set
     /// for arbitrary indices.
+    #[track_caller]
     pub fn table_set_bg_color(&self, target: TableBgTarget, color: impl Into<ImColor32>) {
+        self.assert_in_frame("Ui::table_set_bg_color");
         unsafe {
             sys::igTableSetBgColor(target.bits() as i32, color.into().into(), -1);
         }
@@ -695,12 +729,14 @@ impl Ui {
     /// for more information on how colors work for tables.
     ///
     /// Use [table_set_bg_color](Self::table_set_bg_color) for the current column.
+    #[track_caller]
     pub fn table_set_bg_color_with_column(
         &self,
         target: TableBgTarget,
         color: impl Into<ImColor32>,
         column_index: usize,
     ) {
+        self.assert_in_frame("Ui::table_set_bg_color_with_column");
         unsafe {
             sys::igTableSetBgColor(
                 target.bits() as i32,
@@ -718,7 +754,9 @@ impl Ui {
     ///
     /// Use [table_set_enabled_with_column](Self::table_set_enabled_with_column) to set
     /// for arbitrary indices.
+    #[track_caller]
     pub fn table_set_enabled(&self, enabled: bool) {
+        self.assert_in_frame("Ui::table_set_enabled");
         unsafe { sys::igTableSetColumnEnabled(-1, enabled) }
     }
 
@@ -727,7 +765,9 @@ impl Ui {
     /// Set to false to hide the column. Users can use the context menu to change
     /// this themselves by right-clicking in headers, or right-clicking in columns body
     /// if [TableFlags::CONTEXT_MENU_IN_BODY].
+    #[track_caller]
     pub fn table_set_enabled_with_column(&self, enabled: bool, column_idx: usize) {
+        self.assert_in_frame("Ui::table_set_enabled_with_column");
         unsafe { sys::igTableSetColumnEnabled(column_idx as i32, enabled) }
     }
 
@@ -744,6 +784,51 @@ impl Ui {
             }
         }
     }
+
+    /// Runs `f` inside a two-column property grid, built with [property_row](Self::property_row).
+    ///
+    /// The label column is sized to fit the widest label, and the editor column takes the
+    /// rest of the available width. Returns `None` (and doesn't run `f`) if the table is
+    /// clipped.
+    #[track_caller]
+    pub fn property_grid<R>(&self, str_id: impl AsRef<str>, f: impl FnOnce() -> R) -> Option<R> {
+        self.assert_in_frame("Ui::property_grid");
+        let _table = self.begin_table_with_flags(str_id, 2, TableFlags::empty())?;
+        self.table_setup_column_with(TableColumnSetup {
+            name: "##label",
+            flags: TableColumnFlags::WIDTH_FIXED,
+            init_width_or_weight: 0.0,
+            user_id: Id::default(),
+        });
+        self.table_setup_column_with(TableColumnSetup {
+            name: "##value",
+            flags: TableColumnFlags::WIDTH_STRETCH,
+            init_width_or_weight: 0.0,
+            user_id: Id::default(),
+        });
+        Some(f())
+    }
+
+    /// Emits one row of a [property grid](Self::property_grid): `label` in the left column,
+    /// and whatever `f` emits in the right column.
+    ///
+    /// The label is aligned to the frame padding of the editor, and the next item's width is
+    /// set to fill the column. Each row pushes its own id, so rows with the same label don't
+    /// clash.
+    ///
+    /// Must be called inside [property_grid](Self::property_grid).
+    #[track_caller]
+    pub fn property_row<R>(&self, label: impl AsRef<str>, f: impl FnOnce() -> R) -> R {
+        self.assert_in_frame("Ui::property_row");
+        self.table_next_row();
+        self.table_next_column();
+        self.align_text_to_frame_padding();
+        self.text(label);
+        self.table_next_column();
+        let _id = self.push_id_usize(self.table_row_index());
+        self.set_next_item_width(-f32::MIN_POSITIVE);
+        f()
+    }
 }
 
 /// A struct containing all the data needed to setup a table column header
@@ -884,3 +969,35 @@ create_token!(
     /// Ends the table.
     drop { sys::igEndTable() }
 );
+
+#[test]
+fn test_property_grid_rows() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut values = [1.0f32, 2.0];
+    for _ in 0..2 {
+        let ui = ctx.new_frame();
+        ui.window("Properties")
+            .position([0.0, 0.0], crate::Condition::Always)
+            .size([200.0, 100.0], crate::Condition::Always)
+            .build(|| {
+                let ran = ui.property_grid("grid", || {
+                    let mut ids = [0; 2];
+                    let mut widths = [0.0; 2];
+                    for (i, value) in values.iter_mut().enumerate() {
+                        // Duplicate labels still get distinct editors
+                        ui.property_row("Value", || {
+                            ui.input_float("##edit", value).build();
+                            widths[i] = ui.item_rect_size()[0];
+                            ids[i] = ui.table_row_index();
+                        });
+                    }
+                    assert_eq!(ids, [0, 1]);
+                    assert_eq!(widths[0], widths[1]);
+                    assert!(widths[0] > 0.0);
+                    assert_eq!(ui.table_column_count(), 2);
+                });
+                assert_eq!(ran, Some(()));
+            });
+        let _ = ctx.render();
+    }
+}
@@ -21,3 +21,72 @@ pub fn test_ctx_initialized() -> (ReentrantMutexGuard<'static, ()>, Context) {
     ctx.fonts().build_rgba32_texture();
     (guard, ctx)
 }
+
+pub use crate::headless::{HeadlessFrame, HEADLESS_FONT_TEXTURE};
+
+/// [`crate::headless`], holding the test mutex
+pub fn headless(f: impl FnMut(&crate::Ui)) -> HeadlessFrame {
+    let _guard = TEST_MUTEX.lock();
+    crate::headless(f)
+}
+
+/// The rect of the last item, for the closures of [`click_item`] and [`drag_item`]
+pub fn item_rect(ui: &crate::Ui) -> [[f32; 2]; 2] {
+    [ui.item_rect_min(), ui.item_rect_max()]
+}
+
+/// Builds a frame with `f` for each entry of `mouse_down`, holding the left mouse button as
+/// given, see [`drag_item`].
+pub fn click_item(
+    ctx: &mut Context,
+    mouse_down: &[bool],
+    f: impl FnMut(&crate::Ui) -> [[f32; 2]; 2],
+) {
+    let frames: Vec<_> = mouse_down.iter().map(|&down| (down, [0.0; 2])).collect();
+    drag_item(ctx, crate::MouseButton::Left, &frames, f);
+}
+
+/// Builds a frame with `f` inside a window at the top left corner of the display for each
+/// `(down, offset)` entry of `frames`, holding `button` as given.
+///
+/// `f` returns the rect of the item to click. The mouse is over the center of the rect
+/// returned during the previous frame, moved by `offset`, and outside the display during the
+/// first frame.
+pub fn drag_item(
+    ctx: &mut Context,
+    button: crate::MouseButton,
+    frames: &[(bool, [f32; 2])],
+    mut f: impl FnMut(&crate::Ui) -> [[f32; 2]; 2],
+) {
+    let mut rect: Option<[[f32; 2]; 2]> = None;
+    for &(down, offset) in frames {
+        ctx.io_mut().mouse_pos = match rect {
+            Some([min, max]) => [
+                (min[0] + max[0]) / 2.0 + offset[0],
+                (min[1] + max[1]) / 2.0 + offset[1],
+            ],
+            None => [-f32::MAX, -f32::MAX],
+        };
+        ctx.io_mut().mouse_down[button as usize] = down;
+        let ui = ctx.new_frame();
+        ui.window("Click")
+            .position([0.0, 0.0], crate::Condition::Always)
+            .size([300.0, 300.0], crate::Condition::Always)
+            .build(|| rect = Some(f(ui)));
+        let _ = ctx.render();
+    }
+}
+
+#[test]
+fn test_click_item_clicks_the_returned_item() {
+    let (_guard, mut ctx) = test_ctx_initialized();
+    let mut clicks = Vec::new();
+    click_item(&mut ctx, &[false, true, false], |ui| {
+        ui.button("Above");
+        clicks.push(ui.button("Target"));
+        let rect = item_rect(ui);
+        ui.button("Below");
+        rect
+    });
+    assert_eq!(clicks, [false, false, true]);
+}
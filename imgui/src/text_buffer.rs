@@ -0,0 +1,198 @@
+use std::ops::Range;
+use std::os::raw::c_char;
+
+use crate::sys;
+use crate::Ui;
+
+/// Growable text buffer backed by Dear ImGui's `ImGuiTextBuffer`.
+///
+/// Meant for logs and consoles: text is only ever appended, and the start of every line is
+/// tracked so a [list clipper](crate::ListClipper) can render just the visible lines with
+/// [`Ui::text_unformatted_range`].
+pub struct TextBuffer {
+    raw: *mut sys::ImGuiTextBuffer,
+    line_offsets: Vec<usize>,
+}
+
+impl TextBuffer {
+    /// Creates a new empty buffer.
+    pub fn new() -> Self {
+        Self {
+            raw: unsafe { sys::ImGuiTextBuffer_ImGuiTextBuffer() },
+            line_offsets: vec![0],
+        }
+    }
+
+    /// Appends text to the end of the buffer.
+    pub fn append(&mut self, text: impl AsRef<str>) {
+        let text = text.as_ref();
+        let len = self.len();
+        self.line_offsets.extend(
+            text.bytes()
+                .enumerate()
+                .filter(|&(_, b)| b == b'\n')
+                .map(|(i, _)| len + i + 1),
+        );
+        unsafe {
+            let start = text.as_ptr();
+            let end = start.add(text.len());
+            sys::ImGuiTextBuffer_append(self.raw, start as *const c_char, end as *const c_char);
+        }
+    }
+
+    /// Removes all text from the buffer.
+    pub fn clear(&mut self) {
+        unsafe { sys::ImGuiTextBuffer_clear(self.raw) }
+        self.line_offsets.truncate(1);
+    }
+
+    /// Reserves room for at least `capacity` bytes in total.
+    pub fn reserve(&mut self, capacity: usize) {
+        unsafe { sys::ImGuiTextBuffer_reserve(self.raw, capacity as i32) }
+    }
+
+    /// Returns the length of the buffer in bytes.
+    #[doc(alias = "size")]
+    pub fn len(&self) -> usize {
+        unsafe { sys::ImGuiTextBuffer_size(self.raw) as usize }
+    }
+
+    /// Returns true if the buffer contains no text.
+    #[doc(alias = "empty")]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of lines in the buffer.
+    ///
+    /// Text after the last newline counts as a line, even if empty.
+    pub fn line_count(&self) -> usize {
+        self.line_offsets.len()
+    }
+
+    /// Returns the contents of the buffer.
+    pub fn as_str(&self) -> &str {
+        if self.is_empty() {
+            return "";
+        }
+        unsafe {
+            let start = sys::ImGuiTextBuffer_begin(self.raw) as *const u8;
+            let bytes = std::slice::from_raw_parts(start, self.len());
+            // Only ever appended to from `&str`
+            std::str::from_utf8_unchecked(bytes)
+        }
+    }
+
+    /// Returns the given lines, without the newline at the end of the last one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lines` is out of range for [`line_count`](Self::line_count).
+    pub fn lines(&self, lines: Range<usize>) -> &str {
+        assert!(
+            lines.start <= lines.end && lines.end <= self.line_count(),
+            "line range out of bounds"
+        );
+        if lines.start == lines.end {
+            return "";
+        }
+        let start = self.line_offsets[lines.start];
+        let end = match self.line_offsets.get(lines.end) {
+            Some(&next) => next - 1,
+            None => self.len(),
+        };
+        &self.as_str()[start..end]
+    }
+}
+
+impl Default for TextBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for TextBuffer {
+    fn drop(&mut self) {
+        unsafe { sys::ImGuiTextBuffer_destroy(self.raw) }
+    }
+}
+
+impl Ui {
+    /// Renders the given lines of a [`TextBuffer`] as a single block of text.
+    ///
+    /// Nothing is copied or formatted, which makes this suitable for very large logs. Combine
+    /// it with a [`ListClipper`](crate::ListClipper) to only submit the visible lines:
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # fn log(ui: &Ui, buffer: &TextBuffer) {
+    /// let _spacing = ui.push_style_var(StyleVar::ItemSpacing([0.0, 0.0]));
+    /// let mut clipper = ListClipper::new(buffer.line_count() as i32)
+    ///     .items_height(ui.text_line_height())
+    ///     .begin(ui);
+    /// while clipper.step() {
+    ///     let range = clipper.display_start() as usize..clipper.display_end() as usize;
+    ///     ui.text_unformatted_range(buffer, range);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lines` is out of range for [`TextBuffer::line_count`].
+    #[doc(alias = "TextUnformatted")]
+    pub fn text_unformatted_range(&self, buffer: &TextBuffer, lines: Range<usize>) {
+        self.text(buffer.lines(lines));
+    }
+}
+
+#[test]
+fn test_text_buffer_append() {
+    let mut buffer = TextBuffer::new();
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.line_count(), 1);
+    assert_eq!(buffer.as_str(), "");
+
+    buffer.append("first\nsec");
+    buffer.append("ond\n");
+    buffer.append(String::from("third"));
+    assert_eq!(buffer.len(), 18);
+    assert_eq!(buffer.as_str(), "first\nsecond\nthird");
+    assert_eq!(buffer.line_count(), 3);
+    assert_eq!(buffer.lines(0..1), "first");
+    assert_eq!(buffer.lines(1..3), "second\nthird");
+    assert_eq!(buffer.lines(2..2), "");
+
+    buffer.clear();
+    assert_eq!(buffer.len(), 0);
+    assert_eq!(buffer.line_count(), 1);
+    buffer.append("again\n");
+    assert_eq!(buffer.line_count(), 2);
+    assert_eq!(buffer.lines(0..2), "again\n");
+}
+
+#[test]
+fn test_text_unformatted_range_with_clipper() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut buffer = TextBuffer::new();
+    for i in 0..10_000 {
+        buffer.append(format!("line {}\n", i));
+    }
+    let ui = ctx.frame();
+    let _window = ui
+        .window("Log")
+        .position([0.0, 0.0], crate::Condition::Always)
+        .size([100.0, 200.0], crate::Condition::Always)
+        .begin();
+    let _spacing = ui.push_style_var(crate::StyleVar::ItemSpacing([0.0, 0.0]));
+    let mut clipper = crate::ListClipper::new(buffer.line_count() as i32)
+        .items_height(ui.text_line_height())
+        .begin(ui);
+    let mut rendered = 0;
+    while clipper.step() {
+        let range = clipper.display_start() as usize..clipper.display_end() as usize;
+        rendered += range.len();
+        ui.text_unformatted_range(&buffer, range);
+    }
+    assert!(rendered > 0 && rendered < 50);
+}
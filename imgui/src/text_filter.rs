@@ -1,8 +1,19 @@
 use crate::sys;
 use crate::Ui;
-use std::ptr;
 
 /// Helper to parse and apply text filters
+///
+/// The filter is a comma-separated list of terms, matched case-insensitively as substrings:
+///
+/// - `"aaa"`: passes text containing "aaa"
+/// - `"aaa,bbb"`: passes text containing "aaa" or "bbb"
+/// - `"-aaa"`: passes text not containing "aaa"
+/// - `"-bbb,aaa"`: passes text containing "aaa", unless it also contains "bbb"
+///
+/// Terms are checked in order and the first one that matches decides, so exclusions go before
+/// the terms they restrict. Leading and trailing spaces around terms are ignored. An empty filter passes everything.
+/// The filter keeps its own input buffer (up to 255 bytes), which is edited by
+/// [`draw()`](Self::draw).
 pub struct TextFilter {
     id: String,
     raw: *mut sys::ImGuiTextFilter,
@@ -64,11 +75,10 @@ impl TextFilter {
     ///
     /// [`draw()`](Self::draw) or [`build()`](Self::build) mut be called **before** this function.
     pub fn pass_filter(&self, buf: &str) -> bool {
-        let mut buf = String::from(buf);
-        buf.push('\0');
-        let ptr = buf.as_mut_ptr();
+        let start = buf.as_ptr();
         unsafe {
-            sys::ImGuiTextFilter_PassFilter(self.raw, ptr as *mut sys::cty::c_char, ptr::null())
+            let end = start.add(buf.len());
+            sys::ImGuiTextFilter_PassFilter(self.raw, start as *const _, end as *const _)
         }
     }
 
@@ -95,6 +105,12 @@ impl TextFilter {
     }
 }
 
+impl Drop for TextFilter {
+    fn drop(&mut self) {
+        unsafe { sys::ImGuiTextFilter_destroy(self.raw) }
+    }
+}
+
 impl Ui {
     pub fn text_filter(label: String) -> TextFilter {
         TextFilter::new(label)
@@ -104,3 +120,23 @@ impl Ui {
         TextFilter::new_with_filter(label, filter)
     }
 }
+
+#[test]
+fn test_text_filter_pass_filter() {
+    let filter = TextFilter::new_with_filter(String::from("Filter"), String::from("apple"));
+    filter.build();
+    assert!(filter.is_active());
+    assert!(filter.pass_filter("pineapple"));
+    assert!(filter.pass_filter("APPLE pie"));
+    assert!(!filter.pass_filter("banana"));
+
+    let filter = TextFilter::new_with_filter(String::from("Filter"), String::from("-nan, a"));
+    filter.build();
+    assert!(filter.pass_filter("apple"));
+    assert!(!filter.pass_filter("banana"));
+    assert!(!filter.pass_filter("cherry"));
+
+    filter.clear();
+    assert!(!filter.is_active());
+    assert!(filter.pass_filter("cherry"));
+}
@@ -9,6 +9,51 @@
 ///
 /// In general, if you're looking at this, don't overthink these -- just slap
 /// a '_token` as their binding name and allow them to drop.
+///
+/// Every `begin_*`/`push_*` call in imgui-rs that has a matching end or pop call in
+/// Dear ImGui returns such a token, and there are no public `end_*` functions. Tokens
+/// that need to carry state (e.g. [`TreeNodeToken`](crate::TreeNodeToken) or
+/// [`MultiColorToken`](crate::MultiColorToken)) are written by hand, but follow the same
+/// rules: they are `#[must_use]`, they borrow the [`Ui`](crate::Ui) they were created
+/// from, and their `Drop` impl makes the end call.
+///
+/// Because of that borrow, a token can't outlive the frame it was created in:
+///
+/// ```compile_fail
+/// # use imgui::*;
+/// let mut ctx = Context::create();
+/// let group = {
+///     let ui = ctx.new_frame();
+///     ui.begin_group()
+/// };
+/// ctx.render();
+/// ```
+///
+/// ```compile_fail
+/// # use imgui::*;
+/// let mut ctx = Context::create();
+/// let node = {
+///     let ui = ctx.new_frame();
+///     ui.tree_node("Node")
+/// };
+/// ctx.render();
+/// ```
+///
+/// Ending them inside the frame is fine:
+///
+/// ```no_run
+/// # use imgui::*;
+/// let mut ctx = Context::create();
+/// {
+///     let ui = ctx.new_frame();
+///     let group = ui.begin_group();
+///     if let Some(_node) = ui.tree_node("Node") {
+///         ui.text("Inside");
+///     }
+///     group.end();
+/// }
+/// ctx.render();
+/// ```
 macro_rules! create_token {
     (
         $(#[$struct_meta:meta])*
@@ -132,13 +132,32 @@ impl Ui {
     /// Allows the last item to be overlapped by a subsequent item.
     ///
     /// Both may be activated during the same frame before the later one takes priority.
+    /// Without this, an item placed on top of a hovered item (e.g. a small button drawn over a
+    /// selectable) can't be hovered or clicked at all.
+    ///
+    /// For the later item to actually receive clicks, the overlapped item also has to stop
+    /// claiming the mouse while the later one is hovered. Selectables and tree nodes do both
+    /// when built with their `ALLOW_ITEM_OVERLAP` flag
+    /// ([`SelectableFlags`](crate::SelectableFlags), [`TreeNodeFlags`](crate::TreeNodeFlags)),
+    /// which calls this for you; for other items this only affects hovering.
+    ///
+    /// Newer Dear ImGui versions replace this with `SetNextItemAllowOverlap`, which is called
+    /// before the overlapped item instead. It isn't available in the bundled version.
     #[doc(alias = "SetItemAllowOverlap")]
+    #[track_caller]
     pub fn set_item_allow_overlap(&self) {
+        self.assert_in_frame("Ui::set_item_allow_overlap");
         unsafe { sys::igSetItemAllowOverlap() };
     }
-    /// Makes the last item the default focused item of the window
+    /// Makes the last item the default focused item of the window.
+    ///
+    /// Call this right after the item. It only has an effect when the window is appearing, so
+    /// keyboard/gamepad navigation starts on that item. Only call it for one item per window
+    /// and frame.
     #[doc(alias = "SetItemDefaultFocus")]
+    #[track_caller]
     pub fn set_item_default_focus(&self) {
+        self.assert_in_frame("Ui::set_item_default_focus");
         unsafe { sys::igSetItemDefaultFocus() };
     }
 }
@@ -146,11 +165,34 @@ impl Ui {
 /// # Miscellaneous utilities
 impl Ui {
     /// Returns `true` if the rectangle (of given size, starting from cursor position) is visible
+    ///
+    /// The rectangle starts at the current [screen cursor position](Self::cursor_screen_pos)
+    /// and is tested against the clip rectangle of the current window. This is handy for
+    /// skipping expensive custom content that is scrolled out of view: check the size it would
+    /// occupy, and if it isn't visible, advance the cursor with [`dummy`](Self::dummy) instead
+    /// of drawing it.
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let size = [200.0, 120.0];
+    /// if ui.is_cursor_rect_visible(size) {
+    ///     // ... draw the expensive content
+    /// } else {
+    ///     ui.dummy(size);
+    /// }
+    /// ```
     #[doc(alias = "IsRectVisibleNil")]
     pub fn is_cursor_rect_visible(&self, size: impl Into<MintVec2>) -> bool {
         unsafe { sys::igIsRectVisible_Nil(size.into().into()) }
     }
     /// Returns `true` if the rectangle (in screen coordinates) is visible
+    ///
+    /// Unlike [`is_cursor_rect_visible`](Self::is_cursor_rect_visible), the corners are
+    /// absolute screen positions, as used by the [draw list](Self::get_window_draw_list) and
+    /// returned by e.g. [`item_rect_min`](Self::item_rect_min). They are tested against the
+    /// clip rectangle of the current window.
     #[doc(alias = "IsRectVisibleNilVec2")]
     pub fn is_rect_visible(
         &self,
@@ -161,7 +203,8 @@ impl Ui {
     }
     /// Returns the global imgui-rs time.
     ///
-    /// Incremented by Io::delta_time every frame.
+    /// Incremented by Io::delta_time every frame. Animations driven by this stay in sync with
+    /// the ones of Dear ImGui itself, such as the text cursor blinking.
     #[doc(alias = "GetTime")]
     pub fn time(&self) -> f64 {
         unsafe { sys::igGetTime() }
@@ -209,3 +252,108 @@ impl Ui {
         &*(sys::igGetStyle() as *const Style)
     }
 }
+
+/// # Internal item state
+///
+/// **Unstable**, see [Unstable APIs](crate#unstable-apis).
+#[cfg(feature = "docking")]
+impl Ui {
+    /// Returns how long the currently hovered item has been hovered, in seconds.
+    ///
+    /// Resets to 0.0 whenever a different item (or no item) becomes hovered.
+    pub fn hovered_id_timer(&self) -> f32 {
+        unsafe { (*sys::igGetCurrentContext()).HoveredIdTimer }
+    }
+    /// Returns how long the currently active item has been active, in seconds.
+    ///
+    /// Resets to 0.0 whenever a different item (or no item) becomes active.
+    pub fn active_id_timer(&self) -> f32 {
+        unsafe { (*sys::igGetCurrentContext()).ActiveIdTimer }
+    }
+}
+
+#[cfg(feature = "docking")]
+#[test]
+fn test_hovered_and_active_id_timers() {
+    use crate::test::{click_item, item_rect};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    ctx.io_mut().delta_time = 0.1;
+    let mut timers = Vec::new();
+    // Hover the button for a few frames, then hold it down
+    let mouse_down = [false, false, false, false, true, true, true];
+    click_item(&mut ctx, &mouse_down, |ui| {
+        ui.button("Grow");
+        timers.push((ui.hovered_id_timer(), ui.active_id_timer()));
+        item_rect(ui)
+    });
+    assert_eq!(timers[0], (0.0, 0.0));
+    assert!(timers[3].0 > timers[2].0);
+    assert!(timers[6].1 > timers[5].1);
+    assert_eq!(timers[3].1, 0.0);
+}
+
+#[test]
+fn test_time_and_frame_count_advance_each_frame() {
+    let mut frames = Vec::new();
+    crate::test::headless(|ui| frames.push((ui.time(), ui.frame_count())));
+    let delta = f64::from(1.0f32 / 60.0);
+    assert_eq!(frames, [(delta, 1), (delta * 2.0, 2)]);
+}
+
+#[test]
+fn test_rect_visibility() {
+    crate::test::headless(|ui| {
+        ui.window("Culling")
+            .position([0.0, 0.0], crate::Condition::Always)
+            .size([200.0, 100.0], crate::Condition::Always)
+            .build(|| {
+                assert!(ui.is_cursor_rect_visible([50.0, 20.0]));
+                assert!(ui.is_rect_visible([10.0, 30.0], [60.0, 50.0]));
+                assert!(!ui.is_rect_visible([300.0, 300.0], [350.0, 320.0]));
+
+                // Move the cursor below the bottom edge of the window
+                ui.set_cursor_pos([0.0, 500.0]);
+                assert!(!ui.is_cursor_rect_visible([50.0, 20.0]));
+                ui.dummy([50.0, 20.0]);
+            });
+    });
+}
+
+#[test]
+fn test_set_item_allow_overlap() {
+    use crate::SelectableFlags;
+
+    /// Clicks at `mouse_pos` and returns which of the two overlapping items were pressed
+    fn clicked(flags: SelectableFlags, mouse_pos: [f32; 2]) -> (bool, bool) {
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        let mut result = (false, false);
+        for &down in &[false, false, true, false] {
+            ctx.io_mut().mouse_pos = mouse_pos;
+            ctx.io_mut().mouse_down[0] = down;
+            let ui = ctx.new_frame();
+            ui.window("Overlap")
+                .position([0.0, 0.0], crate::Condition::Always)
+                .size([300.0, 200.0], crate::Condition::Always)
+                .build(|| {
+                    ui.set_cursor_pos([10.0, 40.0]);
+                    result.0 |= ui
+                        .selectable_config("Row")
+                        .size([200.0, 50.0])
+                        .flags(flags)
+                        .build();
+                    ui.set_cursor_pos([20.0, 50.0]);
+                    result.1 |= ui.button_with_size("X", [30.0, 30.0]);
+                });
+            let _ = ctx.render();
+        }
+        result
+    }
+    let on_front = [35.0, 65.0];
+    let on_back = [150.0, 65.0];
+    // The selectable claims the mouse and hides the button on top of it
+    assert_eq!(clicked(SelectableFlags::empty(), on_front), (true, false));
+    let overlap = SelectableFlags::ALLOW_ITEM_OVERLAP;
+    assert_eq!(clicked(overlap, on_front), (false, true));
+    assert_eq!(clicked(overlap, on_back), (true, false));
+}
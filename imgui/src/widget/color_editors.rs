@@ -327,7 +327,9 @@ where
     /// Builds the color editor.
     ///
     /// Returns true if the color value was changed.
+    #[track_caller]
     pub fn build(mut self) -> bool {
+        self.ui.assert_in_frame("ColorEdit3::build");
         self.flags.insert(ColorEditFlags::NO_ALPHA);
 
         let as_vec3: MintVec3 = (*self.value).into();
@@ -355,12 +357,14 @@ where
 impl Ui {
     /// Edits a color of 3 channels. Use [color_edit3_config](Self::color_edit3_config)
     /// for a builder to customize this widget.
+    #[track_caller]
     pub fn color_edit3<Label, C>(&self, label: Label, value: &mut C) -> bool
     where
         Label: AsRef<str>,
         C: Copy + Into<MintVec3>,
         MintVec3: Into<C> + Into<[f32; 3]>,
     {
+        self.assert_in_frame("Ui::color_edit3");
         ColorEdit3 {
             label,
             value,
@@ -547,7 +551,9 @@ where
     /// Builds the color editor.
     ///
     /// Returns true if the color value was changed.
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("ColorEdit4::build");
         let as_vec4: MintVec4 = (*self.value).into();
         let mut as_vec4: [f32; 4] = as_vec4.into();
 
@@ -573,12 +579,14 @@ where
 impl Ui {
     /// Edits a color of 4 channels. Use [color_edit4_config](Self::color_edit4_config)
     /// for a builder to customize this widget.
+    #[track_caller]
     pub fn color_edit4<Label, C>(&self, label: Label, value: &mut C) -> bool
     where
         Label: AsRef<str>,
         C: Copy + Into<MintVec4>,
         MintVec4: Into<C> + Into<[f32; 4]>,
     {
+        self.assert_in_frame("Ui::color_edit4");
         ColorEdit4 {
             label,
             value,
@@ -772,7 +780,9 @@ where
     /// Builds the color picker.
     ///
     /// Returns true if the color value was changed.
+    #[track_caller]
     pub fn build(mut self) -> bool {
+        self.ui.assert_in_frame("ColorPicker3::build");
         self.flags.insert(ColorEditFlags::NO_ALPHA);
         let mut value: [f32; 3] = (*self.value).into().into();
         let changed = unsafe {
@@ -796,12 +806,14 @@ where
 impl Ui {
     /// Edits a color of 3 channels. Use [color_picker3](Self::color_picker3)
     /// for a builder to customize this widget.
+    #[track_caller]
     pub fn color_picker3<Label, C>(&self, label: Label, value: &mut C) -> bool
     where
         Label: AsRef<str>,
         C: Copy + Into<MintVec3>,
         MintVec3: Into<C> + Into<[f32; 3]>,
     {
+        self.assert_in_frame("Ui::color_picker3");
         ColorPicker3 {
             label,
             value,
@@ -1003,7 +1015,9 @@ where
     /// Builds the color picker.
     ///
     /// Returns true if the color value was changed.
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("ColorPicker4::build");
         let mut value: [f32; 4] = (*self.value).into().into();
         let ref_color = self.ref_color.map(|c| c.as_ptr()).unwrap_or(ptr::null());
 
@@ -1029,12 +1043,14 @@ where
 impl Ui {
     /// Edits a color of 4 channels. Use [color_picker4_config](Self::color_picker4_config)
     /// for a builder to customize this widget.
+    #[track_caller]
     pub fn color_picker4<Label, C>(&self, label: Label, value: &mut C) -> bool
     where
         Label: AsRef<str>,
         C: Copy + Into<MintVec4>,
         MintVec4: Into<C> + Into<[f32; 4]>,
     {
+        self.assert_in_frame("Ui::color_picker4");
         ColorPicker4 {
             label,
             value,
@@ -1169,7 +1185,9 @@ impl<'ui, T: AsRef<str>> ColorButton<'ui, T> {
     /// Builds the color button.
     ///
     /// Returns true if this color button was clicked.
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("ColorButton::build");
         unsafe {
             sys::igColorButton(
                 self.ui.scratch_txt(self.desc_id),
@@ -1182,14 +1200,36 @@ impl<'ui, T: AsRef<str>> ColorButton<'ui, T> {
 }
 
 impl Ui {
-    /// Builds a [ColorButton].
+    /// Builds a [ColorButton], a clickable color swatch.
     ///
-    /// Returns true if this color button was clicked.
+    /// Returns true if this color button was clicked. Use
+    /// [`color_button_config`](Self::color_button_config) to set [`ColorEditFlags`], e.g. for the
+    /// preview style or size.
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// # let mut palette = [[1.0, 0.0, 0.0, 1.0]; 4];
+    /// for (i, color) in palette.iter_mut().enumerate() {
+    ///     let _id = ui.push_id_usize(i);
+    ///     if ui.color_button("##swatch", *color) {
+    ///         ui.open_popup("picker");
+    ///     }
+    ///     ui.popup("picker", || {
+    ///         ui.color_picker4("##picker", color);
+    ///     });
+    ///     ui.same_line();
+    /// }
+    /// ```
+    #[doc(alias = "ColorButton")]
+    #[track_caller]
     pub fn color_button<Label: AsRef<str>>(
         &self,
         desc_id: Label,
         color: impl Into<MintVec4>,
     ) -> bool {
+        self.assert_in_frame("Ui::color_button");
         ColorButton {
             desc_id,
             color: color.into().into(),
@@ -1221,10 +1261,83 @@ impl Ui {
     /// Initializes current color editor/picker options (generally on application startup) if you
     /// want to select a default format, picker type, etc. Users will be able to change many
     /// settings, unless you use .options(false) in your widget builders.
+    ///
+    /// The options are shared by all color widgets of the context that don't set the
+    /// corresponding flags themselves, and the right-click options menu of any of them changes
+    /// them for all. Pick at most one flag from each group (display, data type, picker and
+    /// input); groups left empty use Dear ImGui's defaults.
+    ///
+    /// The options are *not* saved in the .ini file, so they reset when the application
+    /// restarts. To keep the user's choice, store it yourself (see `color_edit_options` with the
+    /// `docking` feature) and call this again on startup.
     #[doc(alias = "SetColorEditOptions")]
+    #[track_caller]
     pub fn set_color_edit_options(&self, flags: ColorEditFlags) {
+        self.assert_in_frame("Ui::set_color_edit_options");
         unsafe {
             sys::igSetColorEditOptions(flags.bits() as i32);
         }
     }
+    /// Returns the current color editor/picker options, as set by
+    /// [`set_color_edit_options`](Self::set_color_edit_options) or changed by the user.
+    ///
+    /// **Unstable**, see [Unstable APIs](crate#unstable-apis).
+    #[cfg(feature = "docking")]
+    pub fn color_edit_options(&self) -> ColorEditFlags {
+        let flags = unsafe { (*sys::igGetCurrentContext()).ColorEditOptions };
+        ColorEditFlags::from_bits_truncate(flags as u32)
+    }
+}
+
+#[test]
+fn test_color_button_reports_clicks() {
+    use crate::test::{click_item, item_rect};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut rect = [[0.0; 2]; 2];
+    let mut clicks = Vec::new();
+    click_item(&mut ctx, &[false, false, true, false, false], |ui| {
+        clicks.push(
+            ui.color_button_config("Red", [1.0, 0.0, 0.0, 1.0])
+                .size([40.0, 40.0])
+                .build(),
+        );
+        rect = item_rect(ui);
+        rect
+    });
+    assert_eq!(rect[1][0] - rect[0][0], 40.0);
+    assert_eq!(clicks, [false, false, false, true, false]);
+}
+
+#[test]
+fn test_set_color_edit_options_applies_to_color_edits() {
+    fn vtx_count(options: Option<ColorEditFlags>) -> usize {
+        let mut color = [0.5, 0.25, 1.0];
+        crate::test::headless(|ui| {
+            if let Some(options) = options {
+                ui.set_color_edit_options(options);
+            }
+            ui.window("Colors").build(|| {
+                ui.color_edit3("Tint", &mut color);
+            });
+        })
+        .vtx_count
+    }
+    let default = vtx_count(None);
+    assert_eq!(vtx_count(Some(ColorEditFlags::DISPLAY_RGB)), default);
+    // One hex input field instead of one per component
+    assert!(vtx_count(Some(ColorEditFlags::DISPLAY_HEX)) < default);
+}
+
+#[cfg(feature = "docking")]
+#[test]
+fn test_color_edit_options_round_trip() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    let options = ColorEditFlags::DISPLAY_HSV | ColorEditFlags::PICKER_HUE_WHEEL;
+    ui.set_color_edit_options(options);
+    let current = ui.color_edit_options();
+    assert!(current.contains(options));
+    assert!(!current.contains(ColorEditFlags::DISPLAY_RGB));
+    assert!(!current.contains(ColorEditFlags::PICKER_HUE_BAR));
 }
@@ -146,7 +146,9 @@ impl<'ui, T: AsRef<str>, Preview: AsRef<str>> ComboBox<'ui, T, Preview> {
     ///
     /// Returns `None` if the combo box is not open and no content should be rendered.
     #[must_use]
+    #[track_caller]
     pub fn begin(self) -> Option<ComboBoxToken<'ui>> {
+        self.ui.assert_in_frame("ComboBox::begin");
         let should_render = unsafe {
             let (ptr_one, ptr_two) = self.ui.scratch_txt_with_opt(self.label, self.preview_value);
             sys::igBeginCombo(ptr_one, ptr_two, self.flags.bits() as i32)
@@ -161,7 +163,9 @@ impl<'ui, T: AsRef<str>, Preview: AsRef<str>> ComboBox<'ui, T, Preview> {
     /// Returns the result of the closure, if it is called.
     ///
     /// Note: the closure is not called if the combo box is not open.
+    #[track_caller]
     pub fn build<R, F: FnOnce() -> R>(self, f: F) -> Option<R> {
+        self.ui.assert_in_frame("ComboBox::build");
         self.begin().map(|_combo| f())
     }
 }
@@ -229,11 +233,13 @@ impl Ui {
     /// ```
     #[must_use]
     #[doc(alias = "BeginCombo")]
+    #[track_caller]
     pub fn begin_combo(
         &self,
         label: impl AsRef<str>,
         preview_value: impl AsRef<str>,
     ) -> Option<ComboBoxToken<'_>> {
+        self.assert_in_frame("Ui::begin_combo");
         self.begin_combo_with_flags(label, preview_value, ComboBoxFlags::empty())
     }
 
@@ -248,12 +254,14 @@ impl Ui {
     /// [begin_combo_no_preview]: Ui::begin_combo_no_preview
     #[must_use]
     #[doc(alias = "BeginCombo")]
+    #[track_caller]
     pub fn begin_combo_with_flags(
         &self,
         label: impl AsRef<str>,
         preview_value: impl AsRef<str>,
         flags: ComboBoxFlags,
     ) -> Option<ComboBoxToken<'_>> {
+        self.assert_in_frame("Ui::begin_combo_with_flags");
         ComboBox {
             label,
             preview_value: Some(preview_value),
@@ -277,7 +285,9 @@ impl Ui {
     /// [begin_combo_no_preview_with_flags]: Ui::begin_combo_no_preview_with_flags
     #[must_use]
     #[doc(alias = "BeginCombo")]
+    #[track_caller]
     pub fn begin_combo_no_preview(&self, label: impl AsRef<str>) -> Option<ComboBoxToken<'_>> {
+        self.assert_in_frame("Ui::begin_combo_no_preview");
         self.begin_combo_no_preview_with_flags(label, ComboBoxFlags::empty())
     }
 
@@ -292,11 +302,13 @@ impl Ui {
     /// [begin_combo_no_preview]: Ui::begin_combo_no_preview
     #[must_use]
     #[doc(alias = "BeginCombo")]
+    #[track_caller]
     pub fn begin_combo_no_preview_with_flags(
         &self,
         label: impl AsRef<str>,
         flags: ComboBoxFlags,
     ) -> Option<ComboBoxToken<'_>> {
+        self.assert_in_frame("Ui::begin_combo_no_preview_with_flags");
         ComboBox {
             label,
             preview_value: None::<&'static str>,
@@ -311,6 +323,7 @@ impl Ui {
     /// See [`Ui::begin_combo`] for a more "immediate mode" style API
     /// for creating dynamic combo boxes
     #[doc(alias = "Combo")]
+    #[track_caller]
     pub fn combo<V, L>(
         &self,
         label: impl AsRef<str>,
@@ -321,6 +334,7 @@ impl Ui {
     where
         for<'b> L: Fn(&'b V) -> Cow<'b, str>,
     {
+        self.assert_in_frame("Ui::combo");
         let label_fn = &label_fn;
         let mut result = false;
         let preview_value = items.get(*current_item).map(label_fn);
@@ -357,12 +371,51 @@ impl Ui {
     /// allocating a `Vec<String>` to use this method try using
     /// [`Ui::begin_combo`] instead
     #[doc(alias = "Combo")]
+    #[track_caller]
     pub fn combo_simple_string(
         &self,
         label: impl AsRef<str>,
         current_item: &mut usize,
         items: &[impl AsRef<str>],
     ) -> bool {
+        self.assert_in_frame("Ui::combo_simple_string");
         self.combo(label, current_item, items, |s| Cow::Borrowed(s.as_ref()))
     }
 }
+
+#[test]
+fn test_begin_combo_with_custom_selectables() {
+    use crate::test::{click_item, item_rect};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let items = ["First", "Second", "Third"];
+    let mut selected = 0;
+    let mut was_open = false;
+    // Click the combo to open it, then click the second row
+    let mouse_down = [false, false, true, false, false, true, false, false];
+    click_item(&mut ctx, &mouse_down, |ui| {
+        let combo = ui.begin_combo_with_flags(
+            "items",
+            items[selected],
+            ComboBoxFlags::HEIGHT_LARGE | ComboBoxFlags::POPUP_ALIGN_LEFT,
+        );
+        let mut target = item_rect(ui);
+        if combo.is_none() {
+            return target;
+        }
+        was_open = true;
+        for (index, item) in items.iter().enumerate() {
+            let prefix = if index == selected { "> " } else { "  " };
+            let _id = ui.push_id_usize(index);
+            if ui.selectable(format!("{}{}", prefix, item)) {
+                selected = index;
+            }
+            if index == 1 {
+                target = item_rect(ui);
+            }
+        }
+        target
+    });
+    assert!(was_open);
+    assert_eq!(selected, 1);
+}
@@ -66,7 +66,9 @@ impl<L: AsRef<str>, T: DataTypeKind, F: AsRef<str>> Drag<T, L, F> {
     /// Builds a drag slider that is bound to the given value.
     ///
     /// Returns true if the slider value was changed.
+    #[track_caller]
     pub fn build(self, ui: &Ui, value: &mut T) -> bool {
+        ui.assert_in_frame("Drag::build");
         unsafe {
             let (one, two) = ui.scratch_txt_with_opt(self.label, self.display_format);
 
@@ -91,7 +93,9 @@ impl<L: AsRef<str>, T: DataTypeKind, F: AsRef<str>> Drag<T, L, F> {
     /// Builds a horizontal array of multiple drag sliders attached to the given slice.
     ///
     /// Returns true if any slider value was changed.
+    #[track_caller]
     pub fn build_array(self, ui: &Ui, values: &mut [T]) -> bool {
+        ui.assert_in_frame("Drag::build_array");
         unsafe {
             let (one, two) = ui.scratch_txt_with_opt(self.label, self.display_format);
 
@@ -208,7 +212,9 @@ where
     ///
     /// Returns true if the slider value was changed.
     #[doc(alias = "DragFloatRange2")]
+    #[track_caller]
     pub fn build(self, ui: &Ui, min: &mut f32, max: &mut f32) -> bool {
+        ui.assert_in_frame("DragRange::build");
         let label;
         let mut display_format = std::ptr::null();
         let mut max_display_format = std::ptr::null();
@@ -251,7 +257,9 @@ where
     ///
     /// Returns true if the slider value was changed.
     #[doc(alias = "DragIntRange2")]
+    #[track_caller]
     pub fn build(self, ui: &Ui, min: &mut i32, max: &mut i32) -> bool {
+        ui.assert_in_frame("DragRange::build");
         unsafe {
             let mut display_format = std::ptr::null();
             let mut max_display_format = std::ptr::null();
@@ -16,6 +16,7 @@ pub struct Image {
     uv1: [f32; 2],
     tint_col: [f32; 4],
     border_col: [f32; 4],
+    bg_col: [f32; 4],
 }
 
 impl Image {
@@ -29,6 +30,7 @@ impl Image {
             uv1: [1.0, 1.0],
             tint_col: [1.0, 1.0, 1.0, 1.0],
             border_col: [0.0, 0.0, 0.0, 0.0],
+            bg_col: [0.0, 0.0, 0.0, 0.0],
         }
     }
     /// Sets the image size
@@ -57,9 +59,35 @@ impl Image {
         self.border_col = border_col.into().into();
         self
     }
+    /// Sets the background color (default: no background).
+    ///
+    /// The background fills the whole image area, including the border, and shows through
+    /// transparent parts of the image.
+    pub fn background_col(mut self, bg_col: impl Into<MintVec4>) -> Self {
+        self.bg_col = bg_col.into().into();
+        self
+    }
     /// Builds the image
-    pub fn build(self, _: &Ui) {
+    #[track_caller]
+    pub fn build(self, ui: &Ui) {
+        ui.assert_in_frame("Image::build");
         unsafe {
+            if self.bg_col[3] > 0.0 {
+                let mut min = sys::ImVec2::zero();
+                sys::igGetCursorScreenPos(&mut min);
+                // Dear ImGui adds a pixel on each side for the border
+                let border = if self.border_col[3] > 0.0 { 2.0 } else { 0.0 };
+                let max =
+                    sys::ImVec2::new(min.x + self.size[0] + border, min.y + self.size[1] + border);
+                sys::ImDrawList_AddRectFilled(
+                    sys::igGetWindowDrawList(),
+                    min,
+                    max,
+                    sys::igGetColorU32_Vec4(self.bg_col.into()),
+                    0.0,
+                    0,
+                );
+            }
             sys::igImage(
                 self.texture_id.id() as *mut c_void,
                 self.size.into(),
@@ -163,7 +191,9 @@ impl<'ui, StrId: AsRef<str>> ImageButton<'ui, StrId> {
         self
     }
     /// Builds the image button
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("ImageButton::build");
         unsafe {
             sys::igImageButton(
                 self.ui.scratch_txt(self.str_id),
@@ -215,7 +245,9 @@ impl ImageButtonDeprecated {
         self
     }
     /// Builds the image button
-    pub fn build(self, _: &Ui) -> bool {
+    #[track_caller]
+    pub fn build(self, ui: &Ui) -> bool {
+        ui.assert_in_frame("ImageButtonDeprecated::build");
         unsafe {
             sys::igPushID_Ptr(self.texture_id.id() as *const _);
 
@@ -248,12 +280,33 @@ impl ImageButtonDeprecated {
 }
 
 impl Ui {
+    /// Renders an image on a background, with a border, e.g. for thumbnails on colored tiles.
+    ///
+    /// This is a shortcut for [`Image`] with [`border_col`](Image::border_col) and
+    /// [`background_col`](Image::background_col).
+    #[track_caller]
+    pub fn image_framed(
+        &self,
+        texture_id: TextureId,
+        size: impl Into<MintVec2>,
+        border_col: impl Into<MintVec4>,
+        bg_col: impl Into<MintVec4>,
+    ) {
+        self.assert_in_frame("Ui::image_framed");
+        Image::new(texture_id, size)
+            .border_col(border_col)
+            .background_col(bg_col)
+            .build(self)
+    }
+
+    #[track_caller]
     pub fn image_button(
         &self,
         str_id: impl AsRef<str>,
         texture_id: TextureId,
         size: impl Into<MintVec2>,
     ) -> bool {
+        self.assert_in_frame("Ui::image_button");
         ImageButton {
             str_id,
             texture_id,
@@ -285,3 +338,44 @@ impl Ui {
         }
     }
 }
+
+#[test]
+fn test_image_framed_draw_commands() {
+    use crate::test::{headless, HEADLESS_FONT_TEXTURE};
+    const THUMBNAIL: TextureId = TextureId::new(2);
+    let draw = |framed: bool| {
+        headless(|ui| {
+            ui.window("Assets")
+                .position([0.0, 0.0], crate::Condition::Always)
+                .size([200.0, 200.0], crate::Condition::Always)
+                .build(|| {
+                    if framed {
+                        ui.image_framed(
+                            THUMBNAIL,
+                            [64.0, 64.0],
+                            [1.0, 1.0, 1.0, 1.0],
+                            [0.2, 0.3, 0.8, 1.0],
+                        );
+                    } else {
+                        Image::new(THUMBNAIL, [64.0, 64.0]).build(ui);
+                    }
+                });
+        })
+        .cmds
+    };
+
+    let plain = draw(false);
+    let framed = draw(true);
+    // The background and border share a draw command, drawn before the image on top of them
+    assert_eq!(framed.len(), plain.len() + 1);
+    let image = framed
+        .iter()
+        .position(|cmd| cmd.texture_id == THUMBNAIL)
+        .unwrap();
+    assert_eq!(framed[image].count, 6);
+    let frame = &framed[image - 1];
+    assert_eq!(frame.texture_id, HEADLESS_FONT_TEXTURE);
+    assert_eq!(frame.clip_rect, framed[image].clip_rect);
+    // More than the background rectangle alone
+    assert!(frame.count > 6);
+}
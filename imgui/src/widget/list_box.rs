@@ -39,7 +39,9 @@ impl<T: AsRef<str>> ListBox<T> {
     ///
     /// Returns `None` if the list box is not open and no content should be rendered.
     #[must_use]
+    #[track_caller]
     pub fn begin(self, ui: &Ui) -> Option<ListBoxToken<'_>> {
+        ui.assert_in_frame("ListBox::begin");
         let should_render =
             unsafe { sys::igBeginListBox(ui.scratch_txt(self.label), self.size.into()) };
         if should_render {
@@ -52,7 +54,9 @@ impl<T: AsRef<str>> ListBox<T> {
     /// Returns the result of the closure, if it is called.
     ///
     /// Note: the closure is not called if the list box is not open.
+    #[track_caller]
     pub fn build<R, F: FnOnce() -> R>(self, ui: &Ui, f: F) -> Option<R> {
+        ui.assert_in_frame("ListBox::build");
         self.begin(ui).map(|_list| f())
     }
 }
@@ -66,9 +70,34 @@ create_token!(
     drop { sys::igEndListBox() }
 );
 
+/// # Widgets: List boxes
+impl Ui {
+    /// Begins a list box, which can contain arbitrary widgets rather than just strings.
+    ///
+    /// `size` is in pixels. A width or height of `0.0` uses the default (the current item
+    /// width, and about 7 items high), and a negative value fills the available space up to
+    /// that distance from the edge. The label is displayed to the right of the box; hide it
+    /// with a `"##"` prefix.
+    ///
+    /// Returns `None` if the list box is clipped and its contents shouldn't be rendered.
+    /// The list box ends when the returned token is dropped or `.end()` is called on it.
+    #[must_use]
+    #[doc(alias = "BeginListBox")]
+    #[track_caller]
+    pub fn begin_list_box(
+        &self,
+        label: impl AsRef<str>,
+        size: impl Into<crate::math::MintVec2>,
+    ) -> Option<ListBoxToken<'_>> {
+        self.assert_in_frame("Ui::begin_list_box");
+        ListBox::new(label).size(size).begin(self)
+    }
+}
+
 /// # Convenience functions
 impl<T: AsRef<str>> ListBox<T> {
     /// Builds a simple list box for choosing from a slice of values
+    #[track_caller]
     pub fn build_simple<V, L>(
         self,
         ui: &Ui,
@@ -79,6 +108,7 @@ impl<T: AsRef<str>> ListBox<T> {
     where
         for<'b> L: Fn(&'b V) -> Cow<'b, str>,
     {
+        ui.assert_in_frame("ListBox::build_simple");
         let mut result = false;
         let lb = self;
         if let Some(_cb) = lb.begin(ui) {
@@ -94,3 +124,32 @@ impl<T: AsRef<str>> ListBox<T> {
         result
     }
 }
+
+#[test]
+fn test_begin_list_box_with_custom_rows() {
+    use crate::test::{click_item, item_rect};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut selected = None;
+    click_item(&mut ctx, &[false, false, true, false], |ui| {
+        let mut rect = [[0.0; 2]; 2];
+        let list = ui.begin_list_box("##rows", [200.0, 150.0]).unwrap();
+        for i in 0..3 {
+            // A row with two lines of text
+            let clicked = ui
+                .selectable_config(format!("Row {}\ndetails", i))
+                .selected(selected == Some(i))
+                .build();
+            if clicked {
+                selected = Some(i);
+            }
+            if i == 1 {
+                rect = item_rect(ui);
+            }
+        }
+        list.end();
+        assert_eq!(ui.item_rect_size(), [200.0, 150.0]);
+        rect
+    });
+    assert_eq!(selected, Some(1));
+}
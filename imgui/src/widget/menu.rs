@@ -12,7 +12,9 @@ impl Ui {
     /// Returns `None` if the menu bar is not visible and no content should be rendered.
     #[must_use]
     #[doc(alias = "BeginMainMenuBar")]
+    #[track_caller]
     pub fn begin_main_menu_bar(&self) -> Option<MainMenuBarToken<'_>> {
+        self.assert_in_frame("Ui::begin_main_menu_bar");
         if unsafe { sys::igBeginMainMenuBar() } {
             Some(MainMenuBarToken::new(self))
         } else {
@@ -22,21 +24,28 @@ impl Ui {
     /// Creates a full-screen main menu bar and runs a closure to construct the contents.
     ///
     /// Note: the closure is not called if the menu bar is not visible.
-    #[doc(alias = "BeginMenuBar")]
+    #[doc(alias = "BeginMainMenuBar")]
+    #[track_caller]
     pub fn main_menu_bar<F: FnOnce()>(&self, f: F) {
+        self.assert_in_frame("Ui::main_menu_bar");
         if let Some(_menu_bar) = self.begin_main_menu_bar() {
             f();
         }
     }
     /// Creates and starts appending to the menu bar of the current window.
     ///
+    /// The window must have been created with the `menu_bar` option
+    /// ([`WindowFlags::MENU_BAR`](crate::WindowFlags::MENU_BAR)).
+    ///
     /// Returns `Some(MenuBarToken)` if the menu bar is visible. After content has been
     /// rendered, the token must be ended by calling `.end()`.
     ///
     /// Returns `None` if the menu bar is not visible and no content should be rendered.
     #[must_use]
     #[doc(alias = "BeginMenuBar")]
+    #[track_caller]
     pub fn begin_menu_bar(&self) -> Option<MenuBarToken<'_>> {
+        self.assert_in_frame("Ui::begin_menu_bar");
         if unsafe { sys::igBeginMenuBar() } {
             Some(MenuBarToken::new(self))
         } else {
@@ -45,9 +54,14 @@ impl Ui {
     }
     /// Creates a menu bar in the current window and runs a closure to construct the contents.
     ///
+    /// The window must have been created with the `menu_bar` option
+    /// ([`WindowFlags::MENU_BAR`](crate::WindowFlags::MENU_BAR)).
+    ///
     /// Note: the closure is not called if the menu bar is not visible.
     #[doc(alias = "BeginMenuBar")]
+    #[track_caller]
     pub fn menu_bar<F: FnOnce()>(&self, f: F) {
+        self.assert_in_frame("Ui::menu_bar");
         if let Some(_menu_bar) = self.begin_menu_bar() {
             f();
         }
@@ -64,7 +78,9 @@ impl Ui {
     /// with `enabled` set to `true`.
     #[must_use]
     #[doc(alias = "BeginMenu")]
+    #[track_caller]
     pub fn begin_menu(&self, label: impl AsRef<str>) -> Option<MenuToken<'_>> {
+        self.assert_in_frame("Ui::begin_menu");
         self.begin_menu_with_enabled(label, true)
     }
 
@@ -76,11 +92,13 @@ impl Ui {
     /// Returns `None` if the menu is not visible and no content should be rendered.
     #[must_use]
     #[doc(alias = "BeginMenu")]
+    #[track_caller]
     pub fn begin_menu_with_enabled(
         &self,
         label: impl AsRef<str>,
         enabled: bool,
     ) -> Option<MenuToken<'_>> {
+        self.assert_in_frame("Ui::begin_menu_with_enabled");
         if unsafe { sys::igBeginMenu(self.scratch_txt(label), enabled) } {
             Some(MenuToken::new(self))
         } else {
@@ -94,7 +112,9 @@ impl Ui {
     /// This is the equivalent of [menu_with_enabled](Self::menu_with_enabled)
     /// with `enabled` set to `true`.
     #[doc(alias = "BeginMenu")]
+    #[track_caller]
     pub fn menu<F: FnOnce()>(&self, label: impl AsRef<str>, f: F) {
+        self.assert_in_frame("Ui::menu");
         self.menu_with_enabled(label, true, f);
     }
 
@@ -102,7 +122,9 @@ impl Ui {
     ///
     /// Note: the closure is not called if the menu is not visible.
     #[doc(alias = "BeginMenu")]
+    #[track_caller]
     pub fn menu_with_enabled<F: FnOnce()>(&self, label: impl AsRef<str>, enabled: bool, f: F) {
+        self.assert_in_frame("Ui::menu_with_enabled");
         if let Some(_menu) = self.begin_menu_with_enabled(label, enabled) {
             f();
         }
@@ -117,7 +139,9 @@ impl Ui {
     ///
     /// [`menu_item_config`]: Self::menu_item_config
     #[doc(alias = "MenuItem")]
+    #[track_caller]
     pub fn menu_item(&self, label: impl AsRef<str>) -> bool {
+        self.assert_in_frame("Ui::menu_item");
         self.menu_item_config(label).build()
     }
 
@@ -201,7 +225,9 @@ impl<'ui, Label: AsRef<str>, Shortcut: AsRef<str>> MenuItem<'ui, Label, Shortcut
     ///
     /// Returns true if the menu item is activated.
     #[doc(alias = "MenuItemBool")]
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("MenuItem::build");
         unsafe {
             let (label, shortcut) = self.ui.scratch_txt_with_opt(self.label, self.shortcut);
             sys::igMenuItem_Bool(label, shortcut, self.selected, self.enabled)
@@ -210,7 +236,9 @@ impl<'ui, Label: AsRef<str>, Shortcut: AsRef<str>> MenuItem<'ui, Label, Shortcut
 
     #[doc(alias = "MenuItemBool")]
     /// Builds the menu item using a mutable reference to selected state.
+    #[track_caller]
     pub fn build_with_ref(self, selected: &mut bool) -> bool {
+        self.ui.assert_in_frame("MenuItem::build_with_ref");
         if self.selected(*selected).build() {
             *selected = !*selected;
             true
@@ -246,3 +274,27 @@ create_token!(
     /// Ends a menu
     drop { sys::igEndMenu() }
 );
+
+#[test]
+fn test_menu_bars() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    let mut main_bar_built = false;
+    ui.main_menu_bar(|| {
+        main_bar_built = true;
+        ui.menu("File", || {
+            ui.menu_item("Quit");
+        });
+    });
+    assert!(main_bar_built);
+
+    let mut window_bar_built = false;
+    ui.window("Menus").menu_bar(true).build(|| {
+        ui.menu_bar(|| {
+            window_bar_built = true;
+            ui.menu_with_enabled("Edit", false, || unreachable!());
+        });
+    });
+    assert!(window_bar_built);
+    let _ = ctx.render();
+}
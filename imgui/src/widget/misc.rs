@@ -28,6 +28,7 @@ impl Ui {
     /// with `size` set to `[0.0, 0.0]`, which will size the button to the
     /// label's width in the current style.
     #[doc(alias = "Button")]
+    #[track_caller]
     pub fn button(&self, label: impl AsRef<str>) -> bool {
         self.button_with_size(label, [0.0, 0.0])
     }
@@ -39,33 +40,41 @@ impl Ui {
     /// Setting `size` as `[0.0, 0.0]` will size the button to the label's width in
     /// the current style.
     #[doc(alias = "Button")]
+    #[track_caller]
     pub fn button_with_size(&self, label: impl AsRef<str>, size: impl Into<MintVec2>) -> bool {
+        self.assert_in_frame("Ui::button");
         unsafe { sys::igButton(self.scratch_txt(label), size.into().into()) }
     }
     /// Renders a small clickable button that is easy to embed in text.
     ///
     /// Returns true if this button was clicked.
     #[doc(alias = "SmallButton")]
+    #[track_caller]
     pub fn small_button(&self, label: impl AsRef<str>) -> bool {
+        self.assert_in_frame("Ui::small_button");
         unsafe { sys::igSmallButton(self.scratch_txt(label)) }
     }
     /// Renders a widget with button behaviour without the visual look.
     ///
     /// Returns true if this button was clicked.
     #[doc(alias = "InvisibleButton")]
+    #[track_caller]
     pub fn invisible_button(&self, id: impl AsRef<str>, size: impl Into<MintVec2>) -> bool {
+        self.assert_in_frame("Ui::invisible_button");
         unsafe { sys::igInvisibleButton(self.scratch_txt(id), size.into().into(), 0) }
     }
     /// Renders a widget with button behaviour without the visual look.
     ///
     /// Returns true if this button was clicked.
     #[doc(alias = "InvisibleButton")]
+    #[track_caller]
     pub fn invisible_button_flags(
         &self,
         id: impl AsRef<str>,
         size: impl Into<MintVec2>,
         flags: ButtonFlags,
     ) -> bool {
+        self.assert_in_frame("Ui::invisible_button_flags");
         unsafe {
             sys::igInvisibleButton(
                 self.scratch_txt(id),
@@ -78,27 +87,48 @@ impl Ui {
     ///
     /// Returns true if this button was clicked.
     #[doc(alias = "ArrowButton")]
+    #[track_caller]
     pub fn arrow_button(&self, id: impl AsRef<str>, direction: Direction) -> bool {
+        self.assert_in_frame("Ui::arrow_button");
         unsafe { sys::igArrowButton(self.scratch_txt(id), direction as i32) }
     }
     /// Renders a simple checkbox.
     ///
     /// Returns true if this checkbox was clicked.
     #[doc(alias = "Checkbox")]
+    #[track_caller]
     pub fn checkbox(&self, label: impl AsRef<str>, value: &mut bool) -> bool {
+        self.assert_in_frame("Ui::checkbox");
         unsafe { sys::igCheckbox(self.scratch_txt(label), value as *mut bool) }
     }
     /// Renders a checkbox suitable for toggling bit flags using a mask.
     ///
+    /// The checkbox is checked if all bits of `mask` are set, and shows the mixed (tristate)
+    /// mark if only some of them are. Clicking it sets or clears all bits of `mask`.
+    ///
     /// Returns true if this checkbox was clicked.
+    #[doc(alias = "CheckboxFlags")]
+    #[track_caller]
     pub fn checkbox_flags<T>(&self, label: impl AsRef<str>, flags: &mut T, mask: T) -> bool
     where
         T: Copy + PartialEq + BitOrAssign + BitAndAssign + BitAnd<Output = T> + Not<Output = T>,
     {
-        let mut value = *flags & mask == mask;
-        let pressed = self.checkbox(label, &mut value);
+        self.assert_in_frame("Ui::checkbox_flags");
+        const ALL: u32 = 0b11;
+        let masked = *flags & mask;
+        let none = mask & !mask;
+        // Map the state onto two bits, so Dear ImGui renders the mixed state for us
+        let mut state = if masked == mask {
+            ALL
+        } else if masked == none {
+            0
+        } else {
+            0b01
+        };
+        let pressed =
+            unsafe { sys::igCheckboxFlags_UintPtr(self.scratch_txt(label), &mut state, ALL) };
         if pressed {
-            if value {
+            if state == ALL {
                 *flags |= mask;
             } else {
                 *flags &= !mask;
@@ -110,26 +140,156 @@ impl Ui {
     ///
     /// Returns true if this radio button was clicked.
     #[doc(alias = "RadioButtonBool")]
+    #[track_caller]
     pub fn radio_button_bool(&self, label: impl AsRef<str>, active: bool) -> bool {
+        self.assert_in_frame("Ui::radio_button_bool");
         unsafe { sys::igRadioButton_Bool(self.scratch_txt(label), active) }
     }
     /// Renders a radio button suitable for choosing an arbitrary value.
     ///
     /// Returns true if this radio button was clicked.
     #[doc(alias = "RadioButtonBool")]
+    #[track_caller]
     pub fn radio_button<T>(&self, label: impl AsRef<str>, value: &mut T, button_value: T) -> bool
     where
         T: Copy + PartialEq,
     {
+        self.assert_in_frame("Ui::radio_button");
         let pressed = self.radio_button_bool(label, *value == button_value);
         if pressed {
             *value = button_value;
         }
         pressed
     }
+    /// Renders a row of radio buttons, one for each option, selecting the option at index
+    /// `current`.
+    ///
+    /// `label` is only used as the ID scope of the group, so several groups with the same
+    /// options don't conflict. Returns true if the selection changed.
+    #[track_caller]
+    pub fn radio_group<T: AsRef<str>>(
+        &self,
+        label: impl AsRef<str>,
+        current: &mut usize,
+        options: &[T],
+    ) -> bool {
+        self.assert_in_frame("Ui::radio_group");
+        let _id = self.push_id(label);
+        let mut changed = false;
+        for (index, option) in options.iter().enumerate() {
+            if index > 0 {
+                self.same_line();
+            }
+            let _option_id = self.push_id_usize(index);
+            if self.radio_button_bool(option, *current == index) && *current != index {
+                *current = index;
+                changed = true;
+            }
+        }
+        changed
+    }
     /// Renders a small circle and keeps the cursor on the same line
     #[doc(alias = "Bullet")]
+    #[track_caller]
     pub fn bullet(&self) {
+        self.assert_in_frame("Ui::bullet");
         unsafe { sys::igBullet() };
     }
 }
+
+#[test]
+fn test_radio_group_selects_clicked_option() {
+    use crate::test::{click_item, item_rect};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut current = 0;
+    let mut changed = false;
+    click_item(&mut ctx, &[false, false, true, false], |ui| {
+        changed |= ui.radio_group("mode", &mut current, &["A", "B", "C"]);
+        item_rect(ui)
+    });
+    assert!(changed);
+    assert_eq!(current, 2);
+}
+
+#[test]
+fn test_checkbox_flags_sets_and_clears_bits() {
+    use crate::test::{click_item, item_rect};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut flags: u32 = 0b1000;
+    let mut clicks = 0;
+    let mut states = Vec::new();
+    click_item(&mut ctx, &[false, false, true, false, true, false], |ui| {
+        if ui.checkbox_flags("bits", &mut flags, 0b0110) {
+            clicks += 1;
+            states.push(flags);
+        }
+        item_rect(ui)
+    });
+    assert_eq!(clicks, 2);
+    assert_eq!(states, [0b1110, 0b1000]);
+
+    // A partially set mask is completed by a click
+    let mut partial: u32 = 0b0010;
+    click_item(&mut ctx, &[false, true, false], |ui| {
+        ui.checkbox_flags("bits", &mut partial, 0b0110);
+        item_rect(ui)
+    });
+    assert_eq!(partial, 0b0110);
+}
+
+#[test]
+fn test_invisible_button_reports_clicks() {
+    use crate::test::{click_item, drag_item, item_rect};
+    use crate::MouseButton;
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut clicks = Vec::new();
+    let mut canvas = |ui: &Ui| {
+        let flags = ButtonFlags::MOUSE_BUTTON_LEFT | ButtonFlags::MOUSE_BUTTON_RIGHT;
+        if ui.invisible_button_flags("canvas", [100.0, 100.0], flags) {
+            clicks.push(ui.is_mouse_released(MouseButton::Right));
+        }
+        item_rect(ui)
+    };
+    // Press and release the left button, then the right one
+    click_item(&mut ctx, &[false, false, true, false], &mut canvas);
+    let right = [
+        (false, [0.0; 2]),
+        (false, [0.0; 2]),
+        (true, [0.0; 2]),
+        (false, [0.0; 2]),
+    ];
+    drag_item(&mut ctx, MouseButton::Right, &right, &mut canvas);
+    assert_eq!(clicks, [false, true]);
+}
+
+#[test]
+fn test_button_accepts_any_string_label() {
+    use crate::test::{click_item, item_rect};
+    use crate::{ImStr, ImString};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let label = String::from("Label");
+    let im_string = ImString::new("Label");
+    let im_str: &ImStr = &im_string;
+    let mut clicked = Vec::new();
+    click_item(&mut ctx, &[false, false, true, false], |ui| {
+        // All label types hash to the same id
+        let id = ui.new_id_str("Label");
+        assert_eq!(ui.new_id_str(&label), id);
+        assert_eq!(ui.new_id_str(im_str), id);
+        assert_eq!(ui.new_id_str(&im_string), id);
+
+        if ui.button("Label") {
+            clicked.push("&str");
+        }
+        let rect = item_rect(ui);
+        ui.button(format!("{}##string", label));
+        ui.button(ImString::new("Label##im_string"));
+        ui.button(&*ImString::new("Label##im_str"));
+        rect
+    });
+    assert_eq!(clicked, ["&str"]);
+}
@@ -62,7 +62,9 @@ impl<T: AsRef<str>> ProgressBar<T> {
     }
 
     /// Builds the progress bar
+    #[track_caller]
     pub fn build(self, ui: &Ui) {
+        ui.assert_in_frame("ProgressBar::build");
         unsafe {
             sys::igProgressBar(
                 self.fraction,
@@ -28,7 +28,9 @@ impl Ui {
     ///
     /// [selectable_config]: Self::selectable_config
     #[doc(alias = "Selectable")]
+    #[track_caller]
     pub fn selectable<T: AsRef<str>>(&self, label: T) -> bool {
+        self.assert_in_frame("Ui::selectable");
         self.selectable_config(label).build()
     }
 
@@ -131,7 +133,9 @@ impl<'ui, T: AsRef<str>> Selectable<'ui, T> {
     /// Builds the selectable.
     ///
     /// Returns true if the selectable was clicked.
+    #[track_caller]
     pub fn build(self) -> bool {
+        self.ui.assert_in_frame("Selectable::build");
         unsafe {
             sys::igSelectable_Bool(
                 self.ui.scratch_txt(self.label),
@@ -143,7 +147,9 @@ impl<'ui, T: AsRef<str>> Selectable<'ui, T> {
     }
 
     /// Builds the selectable using a mutable reference to selected state.
+    #[track_caller]
     pub fn build_with_ref(self, selected: &mut bool) -> bool {
+        self.ui.assert_in_frame("Selectable::build_with_ref");
         if self.selected(*selected).build() {
             *selected = !*selected;
             true
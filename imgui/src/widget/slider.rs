@@ -25,6 +25,7 @@ bitflags!(
 
 impl Ui {
     /// Creates a new slider widget. Returns true if the value has been edited.
+    #[track_caller]
     pub fn slider<T: AsRef<str>, K: DataTypeKind>(
         &self,
         label: T,
@@ -32,9 +33,47 @@ impl Ui {
         max: K,
         value: &mut K,
     ) -> bool {
+        self.assert_in_frame("Ui::slider");
         self.slider_config(label, min, max).build(value)
     }
 
+    /// Creates a new vertical slider widget of the given size. Returns true if the value has
+    /// been edited.
+    ///
+    /// Use [`VerticalSlider`] to set a display format or [`SliderFlags`].
+    #[doc(alias = "VSliderFloat", alias = "VSliderInt", alias = "VSliderScalar")]
+    #[track_caller]
+    pub fn vertical_slider<T: AsRef<str>, K: DataTypeKind>(
+        &self,
+        label: T,
+        size: impl Into<MintVec2>,
+        min: K,
+        max: K,
+        value: &mut K,
+    ) -> bool {
+        self.assert_in_frame("Ui::vertical_slider");
+        VerticalSlider::new(label, size, min, max).build(self, value)
+    }
+
+    /// Creates a new angle slider widget, storing the value in radians but displaying and
+    /// limiting it in degrees. Returns true if the value has been edited.
+    ///
+    /// Use [`AngleSlider`] to set a custom display format or [`SliderFlags`].
+    #[doc(alias = "SliderAngle")]
+    #[track_caller]
+    pub fn slider_angle<T: AsRef<str>>(
+        &self,
+        label: T,
+        value_rad: &mut f32,
+        min_degrees: f32,
+        max_degrees: f32,
+    ) -> bool {
+        self.assert_in_frame("Ui::slider_angle");
+        AngleSlider::new(label)
+            .range_degrees(min_degrees, max_degrees)
+            .build(self, value_rad)
+    }
+
     /// Creates an new ubuilt Slider.
     pub fn slider_config<T: AsRef<str>, K: DataTypeKind>(
         &self,
@@ -136,7 +175,9 @@ where
     /// Builds a slider that is bound to the given value.
     ///
     /// Returns true if the slider value was changed.
+    #[track_caller]
     pub fn build(self, value: &mut Data) -> bool {
+        self.ui.assert_in_frame("Slider::build");
         unsafe {
             let (label, display_format) = self
                 .ui
@@ -156,7 +197,9 @@ where
     /// Builds a horizontal array of multiple sliders attached to the given slice.
     ///
     /// Returns true if any slider value was changed.
+    #[track_caller]
     pub fn build_array(self, values: &mut [Data]) -> bool {
+        self.ui.assert_in_frame("Slider::build_array");
         unsafe {
             let (label, display_format) = self
                 .ui
@@ -264,7 +307,9 @@ where
     /// Builds a vertical slider that is bound to the given value.
     ///
     /// Returns true if the slider value was changed.
+    #[track_caller]
     pub fn build(self, ui: &Ui, value: &mut Data) -> bool {
+        ui.assert_in_frame("VerticalSlider::build");
         unsafe {
             let (label, display_format) = ui.scratch_txt_with_opt(self.label, self.display_format);
 
@@ -367,7 +412,9 @@ where
     /// Builds an angle slider that is bound to the given value (in radians).
     ///
     /// Returns true if the slider value was changed.
+    #[track_caller]
     pub fn build(self, ui: &Ui, value_rad: &mut f32) -> bool {
+        ui.assert_in_frame("AngleSlider::build");
         unsafe {
             let (label, display_format) = ui.scratch_txt_two(self.label, self.display_format);
 
@@ -382,3 +429,52 @@ where
         }
     }
 }
+
+#[test]
+fn test_vertical_slider_drag_is_clamped() {
+    use crate::test::{drag_item, item_rect};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut value = 0.5f32;
+    let mut level = 3i32;
+    let mut changed = false;
+    // Press in the middle of the float slider, then drag far above its top
+    let frames = [
+        (false, [0.0, 0.0]),
+        (false, [0.0, 0.0]),
+        (true, [0.0, 0.0]),
+        (true, [0.0, -1000.0]),
+        (false, [0.0, -1000.0]),
+    ];
+    drag_item(&mut ctx, crate::MouseButton::Left, &frames, |ui| {
+        changed |= ui.vertical_slider("##fader", [20.0, 100.0], 0.0, 1.0, &mut value);
+        let rect = item_rect(ui);
+        ui.same_line();
+        ui.vertical_slider("##level", [20.0, 100.0], 0, 10, &mut level);
+        rect
+    });
+    assert!(changed);
+    assert_eq!(value, 1.0);
+    assert_eq!(level, 3);
+}
+
+#[test]
+fn test_slider_angle_uses_degree_range() {
+    use crate::test::{drag_item, item_rect};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut angle = 0.0f32;
+    // Press on the slider, then drag far past its right end
+    let frames = [
+        (false, [0.0, 0.0]),
+        (false, [0.0, 0.0]),
+        (true, [0.0, 0.0]),
+        (true, [1000.0, 0.0]),
+        (false, [1000.0, 0.0]),
+    ];
+    drag_item(&mut ctx, crate::MouseButton::Left, &frames, |ui| {
+        ui.slider_angle("angle", &mut angle, -90.0, 90.0);
+        item_rect(ui)
+    });
+    assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+}
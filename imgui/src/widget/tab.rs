@@ -87,7 +87,9 @@ impl<T: AsRef<str>> TabBar<T> {
     }
 
     #[must_use]
+    #[track_caller]
     pub fn begin(self, ui: &Ui) -> Option<TabBarToken<'_>> {
+        ui.assert_in_frame("TabBar::begin");
         ui.tab_bar_with_flags(self.id, self.flags)
     }
 
@@ -95,7 +97,9 @@ impl<T: AsRef<str>> TabBar<T> {
     /// Returns the result of the closure, if it is called.
     ///
     /// Note: the closure is not called if no tabbar content is visible
+    #[track_caller]
     pub fn build<R, F: FnOnce() -> R>(self, ui: &Ui, f: F) -> Option<R> {
+        ui.assert_in_frame("TabBar::build");
         self.begin(ui).map(|_tab| f())
     }
 }
@@ -144,7 +148,9 @@ impl<'a, T: AsRef<str>> TabItem<'a, T> {
     }
 
     #[must_use]
+    #[track_caller]
     pub fn begin(self, ui: &Ui) -> Option<TabItemToken<'_>> {
+        ui.assert_in_frame("TabItem::begin");
         ui.tab_item_with_flags(self.label, self.opened, self.flags)
     }
 
@@ -152,7 +158,9 @@ impl<'a, T: AsRef<str>> TabItem<'a, T> {
     /// Returns the result of the closure, if it is called.
     ///
     /// Note: the closure is not called if the tab item is not selected
+    #[track_caller]
     pub fn build<R, F: FnOnce() -> R>(self, ui: &Ui, f: F) -> Option<R> {
+        ui.assert_in_frame("TabItem::build");
         self.begin(ui).map(|_tab| f())
     }
 }
@@ -170,17 +178,21 @@ impl Ui {
     /// Creates a tab bar and returns a tab bar token, allowing you to append
     /// Tab items afterwards. This passes no flags. To pass flags explicitly,
     /// use [tab_bar_with_flags](Self::tab_bar_with_flags).
+    #[track_caller]
     pub fn tab_bar(&self, id: impl AsRef<str>) -> Option<TabBarToken<'_>> {
+        self.assert_in_frame("Ui::tab_bar");
         self.tab_bar_with_flags(id, TabBarFlags::empty())
     }
     //
     /// Creates a tab bar and returns a tab bar token, allowing you to append
     /// Tab items afterwards.
+    #[track_caller]
     pub fn tab_bar_with_flags(
         &self,
         id: impl AsRef<str>,
         flags: TabBarFlags,
     ) -> Option<TabBarToken<'_>> {
+        self.assert_in_frame("Ui::tab_bar_with_flags");
         let should_render =
             unsafe { sys::igBeginTabBar(self.scratch_txt(id), flags.bits() as i32) };
 
@@ -199,28 +211,34 @@ impl Ui {
     ///
     /// [tab_item_with_opened]: Self::tab_item_with_opened
     /// [tab_item_with_flags]: Self::tab_item_with_flags
+    #[track_caller]
     pub fn tab_item(&self, label: impl AsRef<str>) -> Option<TabItemToken<'_>> {
+        self.assert_in_frame("Ui::tab_item");
         self.tab_item_with_flags(label, None, TabItemFlags::empty())
     }
 
     /// Creates a new tab item and returns a token if its contents are visible.
     ///
     /// By default, this doesn't pass any flags. See `[tab_item_with_flags]` for more.
+    #[track_caller]
     pub fn tab_item_with_opened(
         &self,
         label: impl AsRef<str>,
         opened: &mut bool,
     ) -> Option<TabItemToken<'_>> {
+        self.assert_in_frame("Ui::tab_item_with_opened");
         self.tab_item_with_flags(label, Some(opened), TabItemFlags::empty())
     }
 
     /// Creates a new tab item and returns a token if its contents are visible.
+    #[track_caller]
     pub fn tab_item_with_flags(
         &self,
         label: impl AsRef<str>,
         opened: Option<&mut bool>,
         flags: TabItemFlags,
     ) -> Option<TabItemToken<'_>> {
+        self.assert_in_frame("Ui::tab_item_with_flags");
         let should_render = unsafe {
             sys::igBeginTabItem(
                 self.scratch_txt(label),
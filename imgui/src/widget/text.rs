@@ -1,6 +1,6 @@
 use std::os::raw::c_char;
 
-use crate::math::MintVec4;
+use crate::math::{MintVec2, MintVec4};
 // use crate::string::ImStr;
 use crate::style::StyleColor;
 use crate::Ui;
@@ -16,7 +16,9 @@ fn fmt_ptr() -> *const c_char {
 impl Ui {
     /// Renders simple text
     #[doc(alias = "TextUnformatted")]
+    #[track_caller]
     pub fn text<T: AsRef<str>>(&self, text: T) {
+        self.assert_in_frame("Ui::text");
         let s = text.as_ref();
         unsafe {
             let start = s.as_ptr();
@@ -25,13 +27,17 @@ impl Ui {
         }
     }
     /// Renders simple text using the given text color
+    #[track_caller]
     pub fn text_colored<T: AsRef<str>>(&self, color: impl Into<MintVec4>, text: T) {
+        self.assert_in_frame("Ui::text_colored");
         let style = self.push_style_color(StyleColor::Text, color);
         self.text(text);
         style.end();
     }
     /// Renders simple text using `StyleColor::TextDisabled` color
+    #[track_caller]
     pub fn text_disabled<T: AsRef<str>>(&self, text: T) {
+        self.assert_in_frame("Ui::text_disabled");
         let color = self.style_color(StyleColor::TextDisabled);
         let style = self.push_style_color(StyleColor::Text, color);
         self.text(text);
@@ -39,18 +45,111 @@ impl Ui {
     }
     /// Renders text wrapped to the end of window (or column)
     #[doc(alias = "TextWrapperd")]
+    #[track_caller]
     pub fn text_wrapped(&self, text: impl AsRef<str>) {
+        self.assert_in_frame("Ui::text_wrapped");
         unsafe { sys::igTextWrapped(fmt_ptr(), self.scratch_txt(text)) }
     }
     /// Render a text + label combination aligned the same way as value+label widgets
     #[doc(alias = "LabelText")]
+    #[track_caller]
     pub fn label_text(&self, label: impl AsRef<str>, text: impl AsRef<str>) {
+        self.assert_in_frame("Ui::label_text");
         let (ptr_one, ptr_two) = self.scratch_txt_two(label, text);
         unsafe { sys::igLabelText(ptr_one, fmt_ptr(), ptr_two) }
     }
     /// Renders text with a little bullet aligned to the typical tree node
     #[doc(alias = "BulletText")]
+    #[track_caller]
     pub fn bullet_text(&self, text: impl AsRef<str>) {
+        self.assert_in_frame("Ui::bullet_text");
         unsafe { sys::igBulletText(fmt_ptr(), self.scratch_txt(text)) }
     }
+    /// Renders a grid of single characters with the current font and text color, e.g. for
+    /// hex editors or terminals.
+    ///
+    /// Cell `(row, col)` shows `char_at(row, col)`, with its upper-left corner at
+    /// `origin + [col * cell_size[0], row * cell_size[1]]` in screen coordinates. All glyphs
+    /// are written straight into the window draw list in one batch, which is much cheaper than
+    /// calling [`text`](Self::text) per cell.
+    ///
+    /// The grid is not an item: it is not interactive, takes no layout space and doesn't move
+    /// the cursor. Characters missing from the font are drawn with its fallback glyph.
+    #[track_caller]
+    pub fn text_grid(
+        &self,
+        origin: impl Into<MintVec2>,
+        cell_size: impl Into<MintVec2>,
+        rows: usize,
+        cols: usize,
+        char_at: impl Fn(usize, usize) -> char,
+    ) {
+        self.assert_in_frame("Ui::text_grid");
+        let origin: [f32; 2] = origin.into().into();
+        let cell_size: [f32; 2] = cell_size.into().into();
+        unsafe {
+            let font = sys::igGetFont();
+            let scale = sys::igGetFontSize() / (*font).FontSize;
+            let color = sys::igGetColorU32_Col(sys::ImGuiCol_Text as i32, 1.0);
+
+            let mut glyphs = Vec::with_capacity(rows * cols);
+            for row in 0..rows {
+                for col in 0..cols {
+                    let glyph = sys::ImFont_FindGlyph(font, char_at(row, col) as sys::ImWchar);
+                    if let Some(glyph) = glyph.as_ref() {
+                        if glyph.Visible() != 0 {
+                            let x = origin[0] + col as f32 * cell_size[0];
+                            let y = origin[1] + row as f32 * cell_size[1];
+                            glyphs.push((x, y, glyph));
+                        }
+                    }
+                }
+            }
+            if glyphs.is_empty() {
+                return;
+            }
+
+            let draw_list = sys::igGetWindowDrawList();
+            sys::ImDrawList_PushTextureID(draw_list, (*(*font).ContainerAtlas).TexID);
+            let count = glyphs.len() as i32;
+            sys::ImDrawList_PrimReserve(draw_list, count * 6, count * 4);
+            for (x, y, glyph) in glyphs {
+                sys::ImDrawList_PrimRectUV(
+                    draw_list,
+                    sys::ImVec2::new(x + glyph.X0 * scale, y + glyph.Y0 * scale),
+                    sys::ImVec2::new(x + glyph.X1 * scale, y + glyph.Y1 * scale),
+                    sys::ImVec2::new(glyph.U0, glyph.V0),
+                    sys::ImVec2::new(glyph.U1, glyph.V1),
+                    color,
+                );
+            }
+            sys::ImDrawList_PopTextureID(draw_list);
+        }
+    }
+}
+
+#[test]
+fn test_text_grid_batches_glyphs() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut counts = Vec::new();
+    for grid in [false, false, true, true] {
+        let ui = ctx.new_frame();
+        ui.window("Grid")
+            .position([0.0, 0.0], crate::Condition::Always)
+            .size([200.0, 100.0], crate::Condition::Always)
+            .build(|| {
+                ui.text("A");
+                if grid {
+                    // Spaces have no glyph quad
+                    let ch = |row, col| if (row + col) % 2 == 0 { 'A' } else { ' ' };
+                    ui.text_grid([10.0, 10.0], [8.0, 13.0], 4, 16, ch);
+                }
+            });
+        let draw_data = ctx.render();
+        let cmds: usize = draw_data.draw_lists().map(|l| l.commands().count()).sum();
+        counts.push((draw_data.total_vtx_count, cmds));
+    }
+    // One quad per visible glyph, and no extra draw calls on top of regular text
+    assert_eq!(counts[3].0 - counts[1].0, 32 * 4, "{:?}", counts);
+    assert_eq!(counts[3].1, counts[1].1, "{:?}", counts);
 }
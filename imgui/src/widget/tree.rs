@@ -93,11 +93,13 @@ impl Ui {
     /// configurations on the tree node.
     ///
     /// [tree_node_config]: Self::tree_node_config
+    #[track_caller]
     pub fn tree_node<I, T>(&self, id: I) -> Option<TreeNodeToken<'_>>
     where
         I: Into<TreeNodeId<T>>,
         T: AsRef<str>,
     {
+        self.assert_in_frame("Ui::tree_node");
         self.tree_node_config(id).push()
     }
 
@@ -120,6 +122,86 @@ impl Ui {
             ui: self,
         }
     }
+
+    /// Constructs a leaf tree node, i.e. one without children, and returns whether it was
+    /// clicked.
+    ///
+    /// This is a tree node with the `LEAF` and `NO_TREE_PUSH_ON_OPEN` flags, so it has no arrow
+    /// and neither indents the following items nor needs to be popped. The label is also used
+    /// as ID; use [`tree_node_config`](Self::tree_node_config) for more options.
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// ui.window("Hierarchy").build(|| {
+    ///     if let Some(_scene) = ui.tree_node("Scene") {
+    ///         if ui.tree_leaf("Camera") {
+    ///             println!("camera selected");
+    ///         }
+    ///         ui.tree_leaf("Light");
+    ///     }
+    /// });
+    /// ```
+    #[doc(alias = "TreeNodeEx")]
+    #[track_caller]
+    pub fn tree_leaf(&self, label: impl AsRef<str>) -> bool {
+        self.assert_in_frame("Ui::tree_leaf");
+        let _leaf = self
+            .tree_node_config(label)
+            .flags(TreeNodeFlags::LEAF | TreeNodeFlags::NO_TREE_PUSH_ON_OPEN)
+            .push();
+        self.is_item_clicked()
+    }
+
+    /// Constructs a tree node that also works as a selectable row, like in a file explorer,
+    /// and pushes it if it is open.
+    ///
+    /// Returns whether the row was clicked to select it, and the token of the node if it is open.
+    /// The node only opens when its arrow is clicked or the row is double clicked, and those
+    /// clicks don't count as selection. The row is highlighted when `selected` is true and spans
+    /// the full width of the window. `flags` are added to the ones needed for this; pass e.g.
+    /// [`TreeNodeFlags::LEAF`] for rows without children.
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// # let mut selected = None;
+    /// ui.window("Files").build(|| {
+    ///     let (clicked, node) = ui.tree_node_selectable("src", selected == Some(0), TreeNodeFlags::empty());
+    ///     if clicked {
+    ///         selected = Some(0);
+    ///     }
+    ///     if let Some(_node) = node {
+    ///         let (clicked, _) = ui.tree_node_selectable("lib.rs", selected == Some(1), TreeNodeFlags::LEAF);
+    ///         if clicked {
+    ///             selected = Some(1);
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    #[track_caller]
+    pub fn tree_node_selectable<I, T>(
+        &self,
+        id: I,
+        selected: bool,
+        flags: TreeNodeFlags,
+    ) -> (bool, Option<TreeNodeToken<'_>>)
+    where
+        I: Into<TreeNodeId<T>>,
+        T: AsRef<str>,
+    {
+        self.assert_in_frame("Ui::tree_node_selectable");
+        let mut flags = flags
+            | TreeNodeFlags::OPEN_ON_ARROW
+            | TreeNodeFlags::OPEN_ON_DOUBLE_CLICK
+            | TreeNodeFlags::SPAN_FULL_WIDTH;
+        flags.set(TreeNodeFlags::SELECTED, selected);
+        let token = self.tree_node_config(id).flags(flags).push();
+        let clicked = self.is_item_clicked() && !self.is_item_toggled_open();
+        (clicked, token)
+    }
 }
 
 /// Builder for a tree node widget
@@ -266,6 +348,22 @@ impl<'a, T: AsRef<str>, L: AsRef<str>> TreeNode<'a, T, L> {
         self
     }
 
+    /// Extends the hit box to the right-most edge, even if the node isn't framed.
+    ///
+    /// Disabled by default.
+    pub fn span_avail_width(mut self, value: bool) -> Self {
+        self.flags.set(TreeNodeFlags::SPAN_AVAIL_WIDTH, value);
+        self
+    }
+
+    /// Extends the hit box to the left-most and right-most edges, ignoring the indentation.
+    ///
+    /// Disabled by default.
+    pub fn span_full_width(mut self, value: bool) -> Self {
+        self.flags.set(TreeNodeFlags::SPAN_FULL_WIDTH, value);
+        self
+    }
+
     /// Left direction may move to this tree node from any of its child.
     ///
     /// Disabled by default.
@@ -281,7 +379,9 @@ impl<'a, T: AsRef<str>, L: AsRef<str>> TreeNode<'a, T, L> {
     /// rendered, the token can be popped by calling `.pop()`.
     ///
     /// Returns `None` if the tree node is not open and no content should be rendered.
+    #[track_caller]
     pub fn push(self) -> Option<TreeNodeToken<'a>> {
+        self.ui.assert_in_frame("TreeNode::push");
         let open = unsafe {
             if self.opened_cond != Condition::Never {
                 sys::igSetNextItemOpen(self.opened, self.opened_cond as i32);
@@ -321,7 +421,9 @@ impl<'a, T: AsRef<str>, L: AsRef<str>> TreeNode<'a, T, L> {
     /// Returns the result of the closure, if it is called.
     ///
     /// Note: the closure is not called if the tree node is not open.
+    #[track_caller]
     pub fn build<R, F: FnOnce() -> R>(self, f: F) -> Option<R> {
+        self.ui.assert_in_frame("TreeNode::build");
         self.push().map(|_node| f())
     }
 }
@@ -336,7 +438,7 @@ pub struct TreeNodeToken<'a>(core::marker::PhantomData<&'a crate::Ui>, bool);
 
 impl<'a> TreeNodeToken<'a> {
     /// Creates a new token type. This takes a bool for the no-op variant on NO_TREE_PUSH_ON_OPEN.
-    pub(crate) fn new(_: &crate::Ui, execute_drop: bool) -> Self {
+    pub(crate) fn new(_: &'a crate::Ui, execute_drop: bool) -> Self {
         Self(std::marker::PhantomData, execute_drop)
     }
 
@@ -441,6 +543,15 @@ impl<T: AsRef<str>> CollapsingHeader<T> {
         self.flags.set(TreeNodeFlags::FRAME_PADDING, value);
         self
     }
+    /// Extends the hit box and frame to the left-most and right-most edges, ignoring the
+    /// indentation.
+    ///
+    /// Disabled by default.
+    #[inline]
+    pub fn span_full_width(mut self, value: bool) -> Self {
+        self.flags.set(TreeNodeFlags::SPAN_FULL_WIDTH, value);
+        self
+    }
 
     /// Begins the collapsing header.
     ///
@@ -448,7 +559,9 @@ impl<T: AsRef<str>> CollapsingHeader<T> {
     ///
     /// This is the same as [build](Self::build) but is provided for consistent naming.
     #[must_use]
+    #[track_caller]
     pub fn begin(self, ui: &Ui) -> bool {
+        ui.assert_in_frame("CollapsingHeader::begin");
         self.build(ui)
     }
 
@@ -459,7 +572,9 @@ impl<T: AsRef<str>> CollapsingHeader<T> {
     /// This is the same as [build_with_close_button](Self::build_with_close_button)
     /// but is provided for consistent naming.
     #[must_use]
+    #[track_caller]
     pub fn begin_with_close_button(self, ui: &Ui, opened: &mut bool) -> bool {
+        ui.assert_in_frame("CollapsingHeader::begin_with_close_button");
         self.build_with_close_button(ui, opened)
     }
 
@@ -468,7 +583,9 @@ impl<T: AsRef<str>> CollapsingHeader<T> {
     /// Returns true if the collapsing header is open and content should be rendered.
     #[must_use]
     #[inline]
+    #[track_caller]
     pub fn build(self, ui: &Ui) -> bool {
+        ui.assert_in_frame("CollapsingHeader::build");
         unsafe {
             sys::igCollapsingHeader_TreeNodeFlags(
                 ui.scratch_txt(self.label),
@@ -482,7 +599,9 @@ impl<T: AsRef<str>> CollapsingHeader<T> {
     /// Returns true if the collapsing header is open and content should be rendered.
     #[must_use]
     #[inline]
+    #[track_caller]
     pub fn build_with_close_button(self, ui: &Ui, opened: &mut bool) -> bool {
+        ui.assert_in_frame("CollapsingHeader::build_with_close_button");
         unsafe {
             sys::igCollapsingHeader_BoolPtr(
                 ui.scratch_txt(self.label),
@@ -496,20 +615,150 @@ impl<T: AsRef<str>> CollapsingHeader<T> {
 impl Ui {
     /// Constructs a new collapsing header
     #[doc(alias = "CollapsingHeader")]
+    #[track_caller]
     pub fn collapsing_header(&self, label: impl AsRef<str>, flags: TreeNodeFlags) -> bool {
+        self.assert_in_frame("Ui::collapsing_header");
         CollapsingHeader::new(label).flags(flags).build(self)
     }
 
     /// Constructs a new collapsing header
     #[doc(alias = "CollapsingHeader")]
+    #[track_caller]
     pub fn collapsing_header_with_close_button(
         &self,
         label: impl AsRef<str>,
         flags: TreeNodeFlags,
         opened: &mut bool,
     ) -> bool {
+        self.assert_in_frame("Ui::collapsing_header_with_close_button");
         CollapsingHeader::new(label)
             .flags(flags)
             .build_with_close_button(self, opened)
     }
 }
+
+#[test]
+fn test_tree_leaf_does_not_indent() {
+    use crate::test::{click_item, item_rect};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut clicks = Vec::new();
+    click_item(&mut ctx, &[false, false, true, false], |ui| {
+        let x = ui.cursor_pos()[0];
+        clicks.push(ui.tree_leaf("Camera"));
+        let rect = item_rect(ui);
+        assert_eq!(ui.cursor_pos()[0], x);
+        ui.tree_leaf("Light");
+        assert_eq!(ui.cursor_pos()[0], x);
+
+        // A regular open tree node indents until it is popped
+        let node = ui.tree_node_config("Group").default_open(true).push();
+        assert!(ui.cursor_pos()[0] > x);
+        drop(node);
+        assert_eq!(ui.cursor_pos()[0], x);
+        rect
+    });
+    assert_eq!(clicks, [false, false, true, false]);
+}
+
+#[test]
+fn test_tree_node_flags_values() {
+    let flags = [
+        (TreeNodeFlags::SELECTED, sys::ImGuiTreeNodeFlags_Selected),
+        (TreeNodeFlags::FRAMED, sys::ImGuiTreeNodeFlags_Framed),
+        (
+            TreeNodeFlags::ALLOW_ITEM_OVERLAP,
+            sys::ImGuiTreeNodeFlags_AllowItemOverlap,
+        ),
+        (
+            TreeNodeFlags::NO_TREE_PUSH_ON_OPEN,
+            sys::ImGuiTreeNodeFlags_NoTreePushOnOpen,
+        ),
+        (
+            TreeNodeFlags::NO_AUTO_OPEN_ON_LOG,
+            sys::ImGuiTreeNodeFlags_NoAutoOpenOnLog,
+        ),
+        (
+            TreeNodeFlags::DEFAULT_OPEN,
+            sys::ImGuiTreeNodeFlags_DefaultOpen,
+        ),
+        (
+            TreeNodeFlags::OPEN_ON_DOUBLE_CLICK,
+            sys::ImGuiTreeNodeFlags_OpenOnDoubleClick,
+        ),
+        (
+            TreeNodeFlags::OPEN_ON_ARROW,
+            sys::ImGuiTreeNodeFlags_OpenOnArrow,
+        ),
+        (TreeNodeFlags::LEAF, sys::ImGuiTreeNodeFlags_Leaf),
+        (TreeNodeFlags::BULLET, sys::ImGuiTreeNodeFlags_Bullet),
+        (
+            TreeNodeFlags::FRAME_PADDING,
+            sys::ImGuiTreeNodeFlags_FramePadding,
+        ),
+        (
+            TreeNodeFlags::SPAN_AVAIL_WIDTH,
+            sys::ImGuiTreeNodeFlags_SpanAvailWidth,
+        ),
+        (
+            TreeNodeFlags::SPAN_FULL_WIDTH,
+            sys::ImGuiTreeNodeFlags_SpanFullWidth,
+        ),
+        (
+            TreeNodeFlags::NAV_LEFT_JUMPS_BACK_HERE,
+            sys::ImGuiTreeNodeFlags_NavLeftJumpsBackHere,
+        ),
+    ];
+    for &(flag, raw) in &flags {
+        assert_eq!(flag.bits(), raw);
+    }
+    let all = flags
+        .iter()
+        .fold(TreeNodeFlags::empty(), |acc, &(f, _)| acc | f);
+    assert_eq!(all, TreeNodeFlags::all());
+}
+
+#[test]
+fn test_tree_node_builder_sets_flags() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    let node = ui
+        .tree_node_config("Files")
+        .open_on_arrow(true)
+        .span_full_width(true);
+    assert_eq!(
+        node.flags,
+        TreeNodeFlags::OPEN_ON_ARROW | TreeNodeFlags::SPAN_FULL_WIDTH
+    );
+    assert_eq!(
+        node.span_full_width(false).span_avail_width(true).flags,
+        TreeNodeFlags::OPEN_ON_ARROW | TreeNodeFlags::SPAN_AVAIL_WIDTH
+    );
+    let header = CollapsingHeader::new("Header").span_full_width(true);
+    assert_eq!(header.flags, TreeNodeFlags::SPAN_FULL_WIDTH);
+}
+
+#[test]
+fn test_tree_node_selectable_reports_selection_and_open() {
+    /// Clicks at `x` (relative to the row) and returns (clicked, open) for each frame
+    fn click_at(x: f32) -> Vec<(bool, bool)> {
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        let mut frames = Vec::new();
+        crate::test::click_item(&mut ctx, &[false, false, true, false, false], |ui| {
+            let (clicked, node) = ui.tree_node_selectable("src", false, TreeNodeFlags::empty());
+            frames.push((clicked, node.is_some()));
+            // An empty rect at `x` into the row
+            let [min, max] = crate::test::item_rect(ui);
+            [[min[0] + x, min[1]], [min[0] + x, max[1]]]
+        });
+        frames
+    }
+    // Clicking the arrow opens the node without selecting it
+    let on_arrow = click_at(5.0);
+    assert!(on_arrow.iter().all(|&(clicked, _)| !clicked));
+    assert_eq!(on_arrow.last(), Some(&(false, true)));
+    // Clicking the row selects it without opening it
+    let on_row = click_at(100.0);
+    assert_eq!(on_row.iter().filter(|&&(clicked, _)| clicked).count(), 1);
+    assert!(on_row.iter().all(|&(_, open)| !open));
+}
@@ -1,6 +1,6 @@
 use std::f32;
 
-use crate::math::MintVec2;
+use crate::math::{MintVec2, MintVec4};
 use crate::window::WindowFlags;
 use crate::Ui;
 use crate::{sys, Id};
@@ -16,6 +16,8 @@ pub struct ChildWindow<'ui> {
     content_size: [f32; 2],
     focused: bool,
     bg_alpha: f32,
+    bg_color: Option<[f32; 4]>,
+    rounding: Option<f32>,
     border: bool,
 }
 
@@ -39,6 +41,8 @@ impl<'ui> ChildWindow<'ui> {
             content_size: [0.0, 0.0],
             focused: false,
             bg_alpha: f32::NAN,
+            bg_color: None,
+            rounding: None,
             border: false,
         }
     }
@@ -87,6 +91,18 @@ impl<'ui> ChildWindow<'ui> {
         self.bg_alpha = bg_alpha;
         self
     }
+    /// Sets the background color of this child window, overriding `StyleColor::ChildBg`.
+    #[inline]
+    pub fn bg_color(mut self, color: impl Into<MintVec4>) -> Self {
+        self.bg_color = Some(color.into().into());
+        self
+    }
+    /// Sets the corner rounding of this child window, overriding `style.child_rounding`.
+    #[inline]
+    pub fn rounding(mut self, rounding: f32) -> Self {
+        self.rounding = Some(rounding);
+        self
+    }
     /// Enables/disables the child window border.
     ///
     /// Disabled by default.
@@ -256,7 +272,9 @@ impl<'ui> ChildWindow<'ui> {
     /// rendered, the token must be ended by calling `.end()`.
     ///
     /// Returns `None` if the window is not visible and no content should be rendered.
+    #[track_caller]
     pub fn begin(self) -> Option<ChildWindowToken<'ui>> {
+        self.ui.assert_in_frame("ChildWindow::begin");
         if self.content_size[0] != 0.0 || self.content_size[1] != 0.0 {
             unsafe { sys::igSetNextWindowContentSize(self.content_size.into()) };
         }
@@ -266,6 +284,14 @@ impl<'ui> ChildWindow<'ui> {
         if self.bg_alpha.is_finite() {
             unsafe { sys::igSetNextWindowBgAlpha(self.bg_alpha) };
         }
+        // The background is drawn when the child begins, so the overrides don't need to remain
+        // pushed for the contents (and shouldn't leak into nested children)
+        if let Some(color) = self.bg_color {
+            unsafe { sys::igPushStyleColor_Vec4(sys::ImGuiCol_ChildBg as i32, color.into()) };
+        }
+        if let Some(rounding) = self.rounding {
+            unsafe { sys::igPushStyleVar_Float(sys::ImGuiStyleVar_ChildRounding as i32, rounding) };
+        }
         let should_render = unsafe {
             sys::igBeginChild_ID(
                 self.id,
@@ -274,6 +300,12 @@ impl<'ui> ChildWindow<'ui> {
                 self.flags.bits() as i32,
             )
         };
+        if self.rounding.is_some() {
+            unsafe { sys::igPopStyleVar(1) };
+        }
+        if self.bg_color.is_some() {
+            unsafe { sys::igPopStyleColor(1) };
+        }
         if should_render {
             Some(ChildWindowToken::new(self.ui))
         } else {
@@ -299,3 +331,142 @@ create_token!(
     /// Ends a window
     drop { sys::igEndChild() }
 );
+
+/// # Child frames
+impl Ui {
+    /// Begins a child frame: a scrollable child window that looks like a framed widget.
+    ///
+    /// Unlike [`child_window`](Self::child_window), it is drawn with the `FrameBg` color,
+    /// frame rounding/border size and frame padding of the style, which makes it a good
+    /// background for e.g. log output. `size` works like [`ChildWindow::size`].
+    ///
+    /// Returns `Some(ChildFrameToken)` if the frame is visible. The frame is ended when the
+    /// token is dropped or `.end()` is called.
+    #[doc(alias = "BeginChildFrame")]
+    #[track_caller]
+    pub fn child_frame(
+        &self,
+        id: impl AsRef<str>,
+        size: impl Into<MintVec2>,
+    ) -> Option<ChildFrameToken<'_>> {
+        self.assert_in_frame("Ui::child_frame");
+        self.child_frame_with_flags(id, size, WindowFlags::empty())
+    }
+    /// Begins a child frame with the given window flags, see [`child_frame`](Self::child_frame).
+    #[doc(alias = "BeginChildFrame")]
+    #[track_caller]
+    pub fn child_frame_with_flags(
+        &self,
+        id: impl AsRef<str>,
+        size: impl Into<MintVec2>,
+        flags: WindowFlags,
+    ) -> Option<ChildFrameToken<'_>> {
+        self.assert_in_frame("Ui::child_frame_with_flags");
+        let id = self.new_id_str(id);
+        let should_render =
+            unsafe { sys::igBeginChildFrame(id.0, size.into().into(), flags.bits() as i32) };
+        if should_render {
+            Some(ChildFrameToken::new(self))
+        } else {
+            unsafe { sys::igEndChildFrame() };
+            None
+        }
+    }
+}
+
+create_token!(
+    /// Tracks a child frame that can be ended by calling `.end()`
+    /// or by dropping
+    pub struct ChildFrameToken<'ui>;
+
+    /// Ends a child frame
+    drop { sys::igEndChildFrame() }
+);
+
+#[test]
+fn test_child_window_style_overrides_are_popped() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    let style = ui.clone_style();
+    ui.window("Parent").build(|| {
+        ui.child_window("Panel")
+            .size([20.0, 20.0])
+            .bg_color([1.0, 0.0, 0.0, 1.0])
+            .rounding(4.0)
+            .build(|| {
+                let inner = ui.clone_style();
+                assert_eq!(
+                    inner[crate::StyleColor::ChildBg],
+                    style[crate::StyleColor::ChildBg]
+                );
+                assert_eq!(inner.child_rounding, style.child_rounding);
+            });
+    });
+    let after = ui.clone_style();
+    assert_eq!(
+        after[crate::StyleColor::ChildBg],
+        style[crate::StyleColor::ChildBg]
+    );
+    assert_eq!(after.child_rounding, style.child_rounding);
+    let _ = ctx.render();
+}
+
+#[test]
+fn test_child_frame_uses_frame_bg() {
+    const FRAME_BG: [f32; 4] = [1.0, 0.0, 1.0, 1.0];
+    const FRAME_BG_PACKED: [u8; 4] = [255, 0, 255, 255];
+
+    fn draws_frame_bg(frame: bool) -> bool {
+        let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+        ctx.style_mut()[crate::StyleColor::FrameBg] = FRAME_BG;
+        let ui = ctx.new_frame();
+        ui.window("Parent")
+            .size([200.0, 200.0], crate::Condition::Always)
+            .build(|| {
+                if frame {
+                    if let Some(_frame) = ui.child_frame("Log", [100.0, 100.0]) {
+                        ui.text("line");
+                    }
+                } else {
+                    ui.child_window("Log")
+                        .size([100.0, 100.0])
+                        .build(|| ui.text("line"));
+                }
+            });
+        ctx.render()
+            .draw_lists()
+            .flat_map(|list| list.vtx_buffer())
+            .any(|vtx| vtx.col == FRAME_BG_PACKED)
+    }
+    assert!(draws_frame_bg(true));
+    assert!(!draws_frame_bg(false));
+}
+
+#[test]
+fn test_child_windows_with_numeric_ids_keep_independent_state() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut scroll = Vec::new();
+    for frame in 0..4 {
+        let ui = ctx.new_frame();
+        scroll.clear();
+        ui.window("Parent")
+            .size([300.0, 300.0], crate::Condition::Always)
+            .build(|| {
+                for item_id in [7, 42] {
+                    ui.child_window_id(ui.new_id_int(item_id))
+                        .size([100.0, 50.0])
+                        .build(|| {
+                            for line in 0..20 {
+                                ui.text(format!("line {}", line));
+                            }
+                            if frame == 1 && item_id == 7 {
+                                ui.set_scroll_y(30.0);
+                            }
+                            scroll.push(ui.scroll_y());
+                        });
+                }
+            });
+        let _ = ctx.render();
+    }
+    assert_eq!(scroll, [30.0, 0.0]);
+}
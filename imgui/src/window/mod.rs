@@ -2,13 +2,19 @@ use bitflags::bitflags;
 use std::f32;
 use std::ptr;
 
+use crate::fonts::atlas::FontId;
 use crate::math::MintVec2;
 use crate::sys;
-use crate::{Condition, Ui};
+use crate::{Condition, FontStackToken, Ui};
 
 pub(crate) mod child_window;
 pub(crate) mod content_region;
 pub(crate) mod scroll;
+#[cfg(feature = "docking")]
+pub(crate) mod viewport;
+
+#[cfg(feature = "docking")]
+pub use self::viewport::ViewportId;
 
 bitflags! {
     /// Window hover check option flags
@@ -159,6 +165,123 @@ impl Ui {
         unsafe { sys::igGetWindowSize(&mut out) };
         out.into()
     }
+    /// Focuses the window with the given name, bringing it to the front.
+    ///
+    /// Does nothing if no window with this name exists.
+    #[doc(alias = "SetWindowFocus")]
+    #[track_caller]
+    pub fn set_window_focus(&self, name: impl AsRef<str>) {
+        self.assert_in_frame("Ui::set_window_focus");
+        unsafe { sys::igSetWindowFocus_Str(self.scratch_txt(name)) }
+    }
+    /// Sets the position of the window with the given name (in screen space), from outside of it.
+    ///
+    /// The condition works as in [`Window::position`], relative to the named window: e.g. with
+    /// [`Condition::FirstUseEver`] the position is only applied if the window has no saved
+    /// settings, and [`Condition::Never`] does nothing. Does nothing either if no window with
+    /// this name exists yet, so call this after the window has been submitted at least once.
+    #[doc(alias = "SetWindowPos")]
+    #[track_caller]
+    pub fn set_window_pos(
+        &self,
+        name: impl AsRef<str>,
+        pos: impl Into<MintVec2>,
+        condition: Condition,
+    ) {
+        self.assert_in_frame("Ui::set_window_pos");
+        if condition != Condition::Never {
+            unsafe {
+                sys::igSetWindowPos_Str(self.scratch_txt(name), pos.into().into(), condition as i32)
+            }
+        }
+    }
+    /// Sets the size of the window with the given name, from outside of it.
+    ///
+    /// A size of 0.0 on an axis makes the window fit its contents on that axis. The condition
+    /// and missing windows are handled as in [`set_window_pos`](Self::set_window_pos).
+    #[doc(alias = "SetWindowSize")]
+    #[track_caller]
+    pub fn set_window_size(
+        &self,
+        name: impl AsRef<str>,
+        size: impl Into<MintVec2>,
+        condition: Condition,
+    ) {
+        self.assert_in_frame("Ui::set_window_size");
+        if condition != Condition::Never {
+            unsafe {
+                sys::igSetWindowSize_Str(
+                    self.scratch_txt(name),
+                    size.into().into(),
+                    condition as i32,
+                )
+            }
+        }
+    }
+    /// Returns information about all windows known to Dear ImGui, including child windows,
+    /// popups and windows that are no longer submitted.
+    ///
+    /// This is a snapshot taken when called: windows created later in the frame are missing,
+    /// and positions and sizes may still change until the frame ends. The snapshot doesn't
+    /// borrow Dear ImGui's window list, so it's fine to submit windows while iterating over it.
+    ///
+    /// **Unstable**, see [Unstable APIs](crate#unstable-apis).
+    #[cfg(feature = "docking")]
+    pub fn windows(&self) -> impl Iterator<Item = WindowInfo> {
+        let windows = unsafe {
+            let list = &(*sys::igGetCurrentContext()).Windows;
+            if list.Data.is_null() {
+                &[]
+            } else {
+                std::slice::from_raw_parts(list.Data, list.Size as usize)
+            }
+        };
+        windows
+            .iter()
+            .map(|&window| unsafe { WindowInfo::from_raw(&*window) })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+/// Snapshot of a window's state, see [`Ui::windows`]
+#[cfg(feature = "docking")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowInfo {
+    /// Full name of the window, including any `##` suffix
+    pub name: String,
+    /// ID of the window, computed from its full name
+    pub id: crate::Id,
+    /// Position (in screen space)
+    pub pos: [f32; 2],
+    /// Outer size, including the title bar and the borders
+    pub size: [f32; 2],
+    /// Whether the window is collapsed to its title bar
+    pub collapsed: bool,
+    /// Whether the window was submitted during the current or the previous frame
+    pub active: bool,
+    /// Whether the window is docked into a visible dock node
+    pub docked: bool,
+    /// Flags the window was last begun with
+    pub flags: WindowFlags,
+}
+
+#[cfg(feature = "docking")]
+impl WindowInfo {
+    unsafe fn from_raw(window: &sys::ImGuiWindow) -> WindowInfo {
+        WindowInfo {
+            name: std::ffi::CStr::from_ptr(window.Name)
+                .to_string_lossy()
+                .into_owned(),
+            id: crate::Id(window.ID),
+            pos: window.Pos.into(),
+            size: window.Size.into(),
+            collapsed: window.Collapsed,
+            active: window.Active || window.WasActive,
+            docked: window.DockIsActive(),
+            flags: WindowFlags::from_bits_truncate(window.Flags as u32),
+        }
+    }
 }
 
 /// Builder for a window
@@ -180,6 +303,7 @@ pub struct Window<'ui, 'a, Label> {
     collapsed_cond: Condition,
     focused: bool,
     bg_alpha: f32,
+    font: Option<FontId>,
 }
 
 impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
@@ -202,6 +326,7 @@ impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
             collapsed_cond: Condition::Never,
             focused: false,
             bg_alpha: f32::NAN,
+            font: None,
         }
     }
     /// Enables the window close button, which sets the passed boolean to false when clicked
@@ -282,6 +407,15 @@ impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
         self.bg_alpha = bg_alpha;
         self
     }
+    /// Sets the font used by the window, including its title bar.
+    ///
+    /// The font is pushed before the window begins and popped after it ends, see
+    /// [`Ui::push_font`] for the requirements on `font`.
+    #[inline]
+    pub fn font(mut self, font: FontId) -> Self {
+        self.font = Some(font);
+        self
+    }
     /// Enables/disables the title bar.
     ///
     /// Enabled by default.
@@ -498,7 +632,10 @@ impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
     ///
     /// Returns `None` if the window is not visible and no content should be rendered.
     #[must_use]
+    #[track_caller]
     pub fn begin(self) -> Option<WindowToken<'ui>> {
+        self.ui.assert_in_frame("Window::begin");
+        let font = self.font.map(|font| self.ui.push_font(font));
         if self.pos_cond != Condition::Never {
             unsafe {
                 sys::igSetNextWindowPos(
@@ -544,7 +681,7 @@ impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
             )
         };
         if should_render {
-            Some(WindowToken::new(self.ui))
+            Some(WindowToken::new(self.ui, font))
         } else {
             unsafe { sys::igEnd() };
             None
@@ -555,16 +692,129 @@ impl<'ui, 'a, Label: AsRef<str>> Window<'ui, 'a, Label> {
     ///
     /// Note: the closure is only called if the window content is
     /// visible (e.g. will not run if window is collapsed).
+    #[track_caller]
     pub fn build<R, F: FnOnce() -> R>(self, f: F) -> Option<R> {
         self.begin().map(|_window| f())
     }
 }
 
-create_token!(
-    /// Tracks a window that can be ended by calling `.end()`
-    /// or by dropping.
-    pub struct WindowToken<'ui>;
+/// Tracks a window that can be ended by calling `.end()`
+/// or by dropping.
+#[must_use]
+pub struct WindowToken<'ui> {
+    _phantom: std::marker::PhantomData<&'ui Ui>,
+    /// Popped when dropped, after the window has ended
+    _font: Option<FontStackToken<'ui>>,
+}
+
+impl<'ui> WindowToken<'ui> {
+    /// Creates a new token type. The font token, if any, is popped after the window ends.
+    pub(crate) fn new(_: &'ui Ui, font: Option<FontStackToken<'ui>>) -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+            _font: font,
+        }
+    }
 
     /// Ends a window
-    drop { sys::igEnd() }
-);
+    #[inline]
+    pub fn end(self) {
+        // left empty for drop
+    }
+}
+
+impl Drop for WindowToken<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::igEnd() }
+    }
+}
+
+#[cfg(feature = "docking")]
+#[test]
+fn test_windows_snapshot() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    for _ in 0..2 {
+        let ui = ctx.new_frame();
+        ui.window("Tool")
+            .position([40.0, 30.0], Condition::Always)
+            .size([200.0, 100.0], Condition::Always)
+            .collapsed(true, Condition::Always)
+            .build(|| ());
+        ui.window("Other").build(|| ());
+        ui.set_window_focus("Tool");
+        let _ = ctx.render();
+    }
+    let ui = ctx.new_frame();
+    let windows: Vec<_> = ui.windows().collect();
+    let names: Vec<_> = windows.iter().map(|w| w.name.as_str()).collect();
+    assert!(names.contains(&"Debug##Default"));
+    assert!(names.contains(&"Other"));
+    let tool = windows.iter().find(|w| w.name == "Tool").unwrap();
+    assert_eq!(tool.pos, [40.0, 30.0]);
+    assert!(tool.collapsed);
+    assert!(tool.active);
+    assert!(!tool.docked);
+    assert!(!tool.flags.contains(WindowFlags::NO_TITLE_BAR));
+    // Focused windows are moved to the end of the list
+    assert_eq!(windows.last().map(|w| w.name.as_str()), Some("Tool"));
+}
+
+#[test]
+fn test_set_window_pos_and_size_by_name() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut placed = ([0.0; 2], [0.0; 2]);
+    for frame in 0..3 {
+        let ui = ctx.new_frame();
+        // Unknown windows are ignored
+        ui.set_window_pos("Missing", [10.0, 10.0], Condition::Always);
+        if frame > 0 {
+            ui.set_window_pos("Tool", [150.0, 120.0], Condition::Always);
+            ui.set_window_size("Tool", [300.0, 200.0], Condition::Always);
+            ui.set_window_pos("Tool", [0.0, 0.0], Condition::Never);
+        }
+        ui.window("Tool").build(|| {
+            placed = (ui.window_pos(), ui.window_size());
+        });
+        let _ = ctx.render();
+    }
+    assert_eq!(placed, ([150.0, 120.0], [300.0, 200.0]));
+}
+
+#[test]
+fn test_window_font() {
+    use crate::{FontConfig, FontSource};
+
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let large_font = ctx.fonts().add_font(&[FontSource::DefaultFontData {
+        config: Some(FontConfig {
+            size_pixels: 26.0,
+            ..FontConfig::default()
+        }),
+    }]);
+    ctx.fonts().build_rgba32_texture();
+    let mut default_size = 0.0;
+    let mut hidden = None;
+    // Collapsing only takes effect in the second frame
+    for _ in 0..2 {
+        let ui = ctx.new_frame();
+        default_size = ui.current_font_size();
+        let built = ui.window("Large").font(large_font).build(|| {
+            assert_eq!(ui.current_font_size(), 26.0);
+        });
+        assert!(built.is_some());
+        assert_eq!(ui.current_font_size(), default_size);
+
+        hidden = Some(
+            ui.window("Collapsed")
+                .font(large_font)
+                .collapsed(true, Condition::Always)
+                .build(|| ())
+                .is_none(),
+        );
+        assert_eq!(ui.current_font_size(), default_size);
+        let _ = ctx.render();
+    }
+    // The font is popped even though this window wasn't visible
+    assert_eq!(hidden, Some(true));
+    assert_ne!(default_size, 26.0);
+}
@@ -1,3 +1,4 @@
+use crate::math::MintVec2;
 use crate::sys;
 use crate::Ui;
 
@@ -31,9 +32,42 @@ impl<'ui> Ui {
     pub fn scroll_max_y(&self) -> f32 {
         unsafe { sys::igGetScrollMaxY() }
     }
+    /// Sets the scrolling position of the next window, before it is created or begun.
+    ///
+    /// Pass a negative value (e.g. `-1.0`) on an axis to keep its current scrolling position.
+    /// Unlike [`set_scroll_y`](Self::set_scroll_y), which is called from inside the window and
+    /// only applies in the next frame, this takes effect in the same frame, so a window can
+    /// open already scrolled without flickering. The value is clamped to the scrollable range,
+    /// which is only known on the first frame if the window has a fixed size and
+    /// [content size](crate::Window::content_size).
+    ///
+    /// ```no_run
+    /// # use imgui::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// # let lines: Vec<String> = Vec::new();
+    /// let line_height = ui.text_line_height_with_spacing();
+    /// if ui.is_key_pressed(Key::End) {
+    ///     // Jump to the last line, keeping the horizontal scroll as it is
+    ///     ui.set_next_window_scroll([-1.0, lines.len() as f32 * line_height]);
+    /// }
+    /// ui.window("Log").build(|| {
+    ///     for line in &lines {
+    ///         ui.text(line);
+    ///     }
+    /// });
+    /// ```
+    #[doc(alias = "SetNextWindowScroll")]
+    #[track_caller]
+    pub fn set_next_window_scroll(&self, scroll: impl Into<MintVec2>) {
+        self.assert_in_frame("Ui::set_next_window_scroll");
+        unsafe { sys::igSetNextWindowScroll(scroll.into().into()) }
+    }
     /// Sets the horizontal scrolling position
     #[doc(alias = "SetScrollX")]
+    #[track_caller]
     pub fn set_scroll_x(&self, scroll_x: f32) {
+        self.assert_in_frame("Ui::set_scroll_x");
         unsafe {
             cfg_if::cfg_if! {
                 if #[cfg(feature = "docking")] {
@@ -46,7 +80,9 @@ impl<'ui> Ui {
     }
     /// Sets the vertical scroll position
     #[doc(alias = "SetScrollY")]
+    #[track_caller]
     pub fn set_scroll_y(&self, scroll_y: f32) {
+        self.assert_in_frame("Ui::set_scroll_y");
         unsafe {
             cfg_if::cfg_if! {
                 if #[cfg(feature = "docking")] {
@@ -61,7 +97,9 @@ impl<'ui> Ui {
     ///
     /// This is the same as [set_scroll_here_x_with_ratio](Self::set_scroll_here_x_with_ratio) but with `ratio` at 0.5.
     #[doc(alias = "SetScrollHereX")]
+    #[track_caller]
     pub fn set_scroll_here_x(&self) {
+        self.assert_in_frame("Ui::set_scroll_here_x");
         self.set_scroll_here_x_with_ratio(0.5);
     }
     /// Adjusts the horizontal scroll position to make the current cursor position visible.
@@ -72,14 +110,18 @@ impl<'ui> Ui {
     /// - `0.5`: center
     /// - `1.0`: right
     #[doc(alias = "SetScrollHereX")]
+    #[track_caller]
     pub fn set_scroll_here_x_with_ratio(&self, center_x_ratio: f32) {
+        self.assert_in_frame("Ui::set_scroll_here_x_with_ratio");
         unsafe { sys::igSetScrollHereX(center_x_ratio) };
     }
     /// Adjusts the vertical scroll position to make the current cursor position visible
     ///
     /// This is the same as [set_scroll_here_y_with_ratio](Self::set_scroll_here_y_with_ratio) but with `ratio` at 0.5.
     #[doc(alias = "SetScrollHereY")]
+    #[track_caller]
     pub fn set_scroll_here_y(&self) {
+        self.assert_in_frame("Ui::set_scroll_here_y");
         self.set_scroll_here_y_with_ratio(0.5);
     }
     /// Adjusts the vertical scroll position to make the current cursor position visible.
@@ -90,7 +132,9 @@ impl<'ui> Ui {
     /// - `0.5`: center
     /// - `1.0`: bottom
     #[doc(alias = "SetScrollHereY")]
+    #[track_caller]
     pub fn set_scroll_here_y_with_ratio(&self, center_y_ratio: f32) {
+        self.assert_in_frame("Ui::set_scroll_here_y_with_ratio");
         unsafe { sys::igSetScrollHereY(center_y_ratio) };
     }
     #[doc(alias = "SetScrollFromPosX")]
@@ -98,7 +142,9 @@ impl<'ui> Ui {
     ///
     /// This is the same as [set_scroll_from_pos_x_with_ratio](Self::set_scroll_from_pos_x_with_ratio)
     /// but with `ratio` at 0.5.
+    #[track_caller]
     pub fn set_scroll_from_pos_x(&self, local_x: f32) {
+        self.assert_in_frame("Ui::set_scroll_from_pos_x");
         self.set_scroll_from_pos_x_with_ratio(local_x, 0.5);
     }
     /// Adjusts the horizontal scroll position to make the given position visible.
@@ -109,7 +155,9 @@ impl<'ui> Ui {
     /// - `0.5`: center
     /// - `1.0`: right
     #[doc(alias = "SetScrollFromPosX")]
+    #[track_caller]
     pub fn set_scroll_from_pos_x_with_ratio(&self, local_x: f32, center_x_ratio: f32) {
+        self.assert_in_frame("Ui::set_scroll_from_pos_x_with_ratio");
         unsafe {
             cfg_if::cfg_if! {
                 if #[cfg(feature = "docking")] {
@@ -125,7 +173,9 @@ impl<'ui> Ui {
     /// This is the same as [set_scroll_from_pos_y_with_ratio](Self::set_scroll_from_pos_y_with_ratio)
     /// but with `ratio` at 0.5.
     #[doc(alias = "SetScrollFromPosY")]
+    #[track_caller]
     pub fn set_scroll_from_pos_y(&self, local_y: f32) {
+        self.assert_in_frame("Ui::set_scroll_from_pos_y");
         self.set_scroll_from_pos_y_with_ratio(local_y, 0.5);
     }
     /// Adjusts the vertical scroll position to make the given position visible.
@@ -136,7 +186,9 @@ impl<'ui> Ui {
     /// - `0.5`: center
     /// - `1.0`: bottom
     #[doc(alias = "SetScrollFromPosY")]
+    #[track_caller]
     pub fn set_scroll_from_pos_y_with_ratio(&self, local_y: f32, center_y_ratio: f32) {
+        self.assert_in_frame("Ui::set_scroll_from_pos_y_with_ratio");
         unsafe {
             cfg_if::cfg_if! {
                 if #[cfg(feature = "docking")] {
@@ -148,3 +200,28 @@ impl<'ui> Ui {
         }
     }
 }
+
+#[test]
+fn test_set_next_window_scroll() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let mut scroll = Vec::new();
+    for frame in 0..3 {
+        let ui = ctx.new_frame();
+        match frame {
+            0 => ui.set_next_window_scroll([-1.0, 300.0]),
+            2 => ui.set_next_window_scroll([-1.0, 10_000.0]),
+            _ => (),
+        }
+        ui.window("Log")
+            .size([200.0, 100.0], crate::Condition::Always)
+            .content_size([0.0, 1000.0])
+            .build(|| scroll.push((ui.scroll_x(), ui.scroll_y(), ui.scroll_max_y())));
+        let _ = ctx.render();
+    }
+    // Applied in the frame it was set, and kept afterwards
+    assert_eq!((scroll[0].0, scroll[0].1), (0.0, 300.0));
+    assert_eq!(scroll[1].1, 300.0);
+    // Clamped to the scrollable range
+    assert!(scroll[2].2 > 300.0);
+    assert_eq!(scroll[2].1, scroll[2].2);
+}
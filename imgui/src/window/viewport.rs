@@ -0,0 +1,82 @@
+use crate::sys;
+use crate::Ui;
+
+/// Unique ID of a viewport, i.e. the main window of the application or one of the platform
+/// windows created for windows dragged outside of it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ViewportId(pub(crate) u32);
+
+impl ViewportId {
+    /// Returns the raw Dear ImGui id of the viewport
+    pub const fn id(self) -> u32 {
+        self.0
+    }
+}
+
+/// # Viewports
+impl Ui {
+    /// Returns the id of the main viewport, i.e. the main window of the application.
+    #[doc(alias = "GetMainViewport")]
+    pub fn main_viewport_id(&self) -> ViewportId {
+        ViewportId(unsafe { (*sys::igGetMainViewport()).ID })
+    }
+    /// Returns the position and size of the work area of the main viewport (in screen space).
+    ///
+    /// The work area is the part of the viewport not covered by the main menu bar.
+    #[doc(alias = "GetMainViewport")]
+    pub fn main_viewport_work_area(&self) -> ([f32; 2], [f32; 2]) {
+        let viewport = unsafe { &*sys::igGetMainViewport() };
+        (viewport.WorkPos.into(), viewport.WorkSize.into())
+    }
+    /// Returns the id of the viewport the current window is in
+    #[doc(alias = "GetWindowViewport")]
+    pub fn window_viewport_id(&self) -> ViewportId {
+        ViewportId(unsafe { (*sys::igGetWindowViewport()).ID })
+    }
+    /// Assigns the next window to the given viewport.
+    ///
+    /// Without this, the viewport of a window follows its position, which for a window covering
+    /// a viewport can flicker between viewports while platform windows move. The usual recipe
+    /// for a full-screen host window (e.g. for a dock space) over the main viewport is:
+    ///
+    /// ```no_run
+    /// # let mut ctx = imgui::Context::create();
+    /// # let ui = ctx.frame();
+    /// let (pos, size) = ui.main_viewport_work_area();
+    /// ui.set_next_window_viewport(ui.main_viewport_id());
+    /// ui.window("Host")
+    ///     .position(pos, imgui::Condition::Always)
+    ///     .size(size, imgui::Condition::Always)
+    ///     .flags(
+    ///         imgui::WindowFlags::NO_DECORATION
+    ///             | imgui::WindowFlags::NO_MOVE
+    ///             | imgui::WindowFlags::NO_DOCKING
+    ///             | imgui::WindowFlags::NO_BRING_TO_FRONT_ON_FOCUS,
+    ///     )
+    ///     .build(|| {
+    ///         // submit the dock space and the main menu here
+    ///     });
+    /// ```
+    #[doc(alias = "SetNextWindowViewport")]
+    #[track_caller]
+    pub fn set_next_window_viewport(&self, viewport: ViewportId) {
+        self.assert_in_frame("Ui::set_next_window_viewport");
+        unsafe { sys::igSetNextWindowViewport(viewport.0) }
+    }
+}
+
+#[test]
+fn test_set_next_window_viewport() {
+    let (_guard, mut ctx) = crate::test::test_ctx_initialized();
+    let ui = ctx.new_frame();
+    let main = ui.main_viewport_id();
+    assert_ne!(main.id(), 0);
+    let (pos, size) = ui.main_viewport_work_area();
+    assert_eq!(pos, [0.0, 0.0]);
+    assert_eq!(size, ui.io().display_size);
+    ui.set_next_window_viewport(main);
+    ui.window("Host").build(|| {
+        assert_eq!(ui.window_viewport_id(), main);
+    });
+    let _ = ctx.render();
+}